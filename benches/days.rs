@@ -0,0 +1,111 @@
+//! Benchmarks parsing and each part of every day against the inputs in `res/`, then prints a
+//! summary table of the mean times.
+//!
+//! Run with `cargo bench`, optionally filtering by benchmark name (`cargo bench -- day07`). To
+//! compare against an earlier run, save it with `cargo bench -- --save-baseline <name>` and then
+//! run `cargo bench -- --baseline <name>`; the summary table will include the change for each
+//! benchmark.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use aoc2023::{
+    day01::Day01, day02::Day02, day03::Day03, day04::Day04, day05::Day05, day06::Day06,
+    day07::Day07, day08::Day08, day09::Day09, day10::Day10, day11::Day11, day12::Day12, Solver,
+};
+use criterion::{black_box, Criterion};
+
+const BENCHMARKS: [&str; 3] = ["parse", "part_a", "part_b"];
+
+fn bench_day<S: Solver>(c: &mut Criterion) {
+    let input = fs::read_to_string(format!("res/input{:02}.txt", S::DAY)).unwrap();
+    let parsed = S::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part_a", |b| b.iter(|| S::part_a(black_box(&parsed))));
+    group.bench_function("part_b", |b| b.iter(|| S::part_b(black_box(&parsed))));
+    group.finish();
+}
+
+fn output_directory() -> PathBuf {
+    let target = std::env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".to_string());
+    Path::new(&target).join("criterion")
+}
+
+/// Reads the mean time in nanoseconds that criterion recorded for a benchmark, if it was written
+/// after `since`.
+fn mean_time(dir: &Path, since: SystemTime) -> Option<f64> {
+    let path = dir.join("estimates.json");
+    if fs::metadata(&path).ok()?.modified().ok()? < since {
+        return None;
+    }
+    let estimates: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    estimates["mean"]["point_estimate"].as_f64()
+}
+
+fn format_time(ns: f64) -> String {
+    match ns {
+        ns if ns < 1e3 => format!("{:.1} ns", ns),
+        ns if ns < 1e6 => format!("{:.1} µs", ns / 1e3),
+        ns if ns < 1e9 => format!("{:.1} ms", ns / 1e6),
+        ns => format!("{:.2} s", ns / 1e9),
+    }
+}
+
+/// Prints the mean time of every benchmark run since `start`, along with the change relative to
+/// the baseline named on the command line, if any.
+fn print_summary(start: SystemTime) {
+    let args = std::env::args().collect::<Vec<_>>();
+    let baseline = args
+        .iter()
+        .position(|arg| arg == "--baseline" || arg == "--baseline-lenient")
+        .and_then(|i| args.get(i + 1));
+
+    println!();
+    println!("{:<8}{:>20}{:>20}{:>20}", "", "parse", "part A", "part B");
+    for day in aoc2023::DAYS {
+        let group_dir = output_directory().join(format!("day{:02}", day));
+        let cells = BENCHMARKS.map(|benchmark| {
+            let dir = group_dir.join(benchmark);
+            let time = mean_time(&dir.join("new"), start)?;
+            let change = baseline
+                .and_then(|baseline| mean_time(&dir.join(baseline), SystemTime::UNIX_EPOCH))
+                .map(|base| format!(" ({:+.0}%)", (time / base - 1.0) * 100.0))
+                .unwrap_or_default();
+            Some(format!("{}{}", format_time(time), change))
+        });
+        if cells.iter().any(Option::is_some) {
+            let [parse, part_a, part_b] = cells.map(|cell| cell.unwrap_or_else(|| "-".to_string()));
+            println!("day {:02}  {:>20}{:>20}{:>20}", day, parse, part_a, part_b);
+        }
+    }
+}
+
+fn main() {
+    let start = SystemTime::now();
+    let mut c = Criterion::default()
+        .output_directory(&output_directory())
+        .configure_from_args();
+
+    bench_day::<Day01>(&mut c);
+    bench_day::<Day02>(&mut c);
+    bench_day::<Day03>(&mut c);
+    bench_day::<Day04>(&mut c);
+    bench_day::<Day05>(&mut c);
+    bench_day::<Day06>(&mut c);
+    bench_day::<Day07>(&mut c);
+    bench_day::<Day08>(&mut c);
+    bench_day::<Day09>(&mut c);
+    bench_day::<Day10>(&mut c);
+    bench_day::<Day11>(&mut c);
+    bench_day::<Day12>(&mut c);
+
+    c.final_summary();
+    print_summary(start);
+}
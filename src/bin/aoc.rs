@@ -0,0 +1,339 @@
+use std::{
+    io::{IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc2023::{
+    day07::{Card, Day07, Rules},
+    day08::Day08,
+    day10::{self, Day10},
+    grid::Pos,
+    solve_day, Part, Solver, DAYS,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solvers for one or more days
+    Run {
+        /// Day to run: a single day (7), a range (3-9), a comma separated list (1,4-6) or "all"
+        #[arg(value_parser = parse_days)]
+        days: Days,
+
+        /// Which part of the puzzle to solve
+        #[arg(long, value_enum, default_value_t = PartArg::Both)]
+        part: PartArg,
+
+        /// Puzzle input file, or "-" for stdin. Defaults to res/inputNN.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+
+    /// Print the day 07 hands ranked from weakest to strongest, with how each one was classified
+    CamelCards {
+        /// Which rules to rank the hands by
+        #[arg(long, value_enum, default_value_t = RulesArg::Standard)]
+        rules: RulesArg,
+
+        /// Puzzle input file, or "-" for stdin. Defaults to res/input07.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+
+    /// Export the day 08 network in Graphviz DOT format, highlighting the cycle each ghost ends in
+    NetworkDot {
+        /// Pattern matching the start nodes, where ? matches any character and * any run of them
+        #[arg(long, default_value = "*A")]
+        starts: String,
+
+        /// Pattern matching the end nodes
+        #[arg(long, default_value = "*Z")]
+        ends: String,
+
+        /// Puzzle input file, or "-" for stdin. Defaults to res/input08.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// File to write the graph to. Defaults to stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Draw the day 10 pipe loop with box-drawing characters, marking the enclosed tiles
+    RenderPipes {
+        /// Whether to use ANSI colours. By default they're used when writing to a terminal
+        #[arg(long, value_enum, default_value_t = ColourArg::Auto)]
+        colour: ColourArg,
+
+        /// Puzzle input file, or "-" for stdin. Defaults to res/input10.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// File to write the drawing to. Defaults to stdout
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+
+    /// List every closed loop and dangling set of pipes on a day 10 map, with their enclosed areas
+    PipeComponents {
+        /// Only list the pipes connected to this tile, given as "x,y", walking any loop from it
+        #[arg(long, value_parser = parse_pos)]
+        anchor: Option<Pos>,
+
+        /// Puzzle input file, or "-" for stdin. Defaults to res/input10.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum PartArg {
+    A,
+    B,
+    Both,
+}
+
+impl PartArg {
+    fn parts(self) -> &'static [Part] {
+        match self {
+            PartArg::A => &[Part::A],
+            PartArg::B => &[Part::B],
+            PartArg::Both => &[Part::A, Part::B],
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum RulesArg {
+    /// Part A: no wild cards
+    Standard,
+    /// Part B: jacks are jokers
+    Jokers,
+}
+
+impl RulesArg {
+    fn rules(self) -> Rules {
+        match self {
+            RulesArg::Standard => Rules::standard(),
+            RulesArg::Jokers => Rules::jokers(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ColourArg {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Clone)]
+struct Days(Vec<u32>);
+
+fn parse_day(s: &str) -> Result<u32> {
+    let day = s
+        .trim()
+        .parse::<u32>()
+        .with_context(|| format!("Invalid day {:?}", s))?;
+    ensure!(
+        DAYS.contains(&day),
+        "Day {} is not in the range {}-{}",
+        day,
+        DAYS.start(),
+        DAYS.end()
+    );
+    Ok(day)
+}
+
+fn parse_days(s: &str) -> Result<Days> {
+    if s == "all" {
+        return Ok(Days(DAYS.collect()));
+    }
+
+    let mut days = Vec::new();
+    for item in s.split(',') {
+        match item.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                ensure!(first <= last, "Invalid day range {:?}", item);
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(item)?),
+        }
+    }
+    Ok(Days(days))
+}
+
+fn parse_pos(s: &str) -> Result<Pos> {
+    let (x, y) = s
+        .split_once(',')
+        .with_context(|| format!("Invalid position {:?}", s))?;
+    let coordinate = |c: &str| {
+        c.trim()
+            .parse()
+            .with_context(|| format!("Invalid position {:?}", s))
+    };
+    Ok(Pos::new(coordinate(x)?, coordinate(y)?))
+}
+
+fn read_input(path: &Path) -> Result<String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {:?}", path))
+    }
+}
+
+fn run(days: &[u32], parts: &[Part], input: Option<PathBuf>) -> Result<()> {
+    let input = match input {
+        Some(_) if days.len() > 1 => bail!("--input can only be used when running a single day"),
+        Some(path) => Some(read_input(&path)?),
+        None => None,
+    };
+
+    for day in days {
+        let input = match &input {
+            Some(input) => input.clone(),
+            None => read_input(&default_input(*day))?,
+        };
+        let answers =
+            solve_day(*day, &input, parts).with_context(|| format!("Day {:02} failed", day))?;
+        for (part, answer) in parts.iter().zip(answers) {
+            println!("Day {:02}, part {}: {}", day, part, answer);
+        }
+    }
+    Ok(())
+}
+
+fn default_input(day: u32) -> PathBuf {
+    PathBuf::from(format!("res/input{:02}.txt", day))
+}
+
+fn camel_cards(rules: &Rules, input: Option<PathBuf>) -> Result<()> {
+    let input = read_input(&input.unwrap_or_else(|| default_input(Day07::DAY)))?;
+    let hands = Day07::parse(&input)?;
+
+    println!(
+        "{:>6}  {:<5}  {:<5}  {:<14}{:>8}{:>12}",
+        "rank", "hand", "as", "type", "bid", "winnings"
+    );
+    let mut total = 0;
+    for (i, hand) in rules.rank(&hands).into_iter().enumerate() {
+        let explanation = rules.explain(hand);
+        let substituted = if explanation.substitutions.is_empty() {
+            String::new()
+        } else {
+            explanation.cards.iter().map(Card::to_string).collect()
+        };
+        let winnings = (i + 1) * hand.bid();
+        total += winnings;
+        println!(
+            "{:>6}  {}  {:<5}  {:<14}{:>8}{:>12}",
+            i + 1,
+            hand,
+            substituted,
+            format!("{:?}", explanation.hand_type),
+            hand.bid(),
+            winnings
+        );
+    }
+    println!("{:>56}", total);
+    Ok(())
+}
+
+fn write_output(output: Option<PathBuf>, contents: &str) -> Result<()> {
+    match output {
+        Some(path) => {
+            std::fs::write(&path, contents).with_context(|| format!("Failed to write {:?}", path))
+        }
+        None => {
+            print!("{}", contents);
+            Ok(())
+        }
+    }
+}
+
+fn network_dot(
+    starts: &str,
+    ends: &str,
+    input: Option<PathBuf>,
+    output: Option<PathBuf>,
+) -> Result<()> {
+    let input = read_input(&input.unwrap_or_else(|| default_input(Day08::DAY)))?;
+    let (directions, network) = Day08::parse(&input)?;
+    write_output(output, &network.to_dot(&directions, starts, ends))
+}
+
+fn render_pipes(colour: ColourArg, input: Option<PathBuf>, output: Option<PathBuf>) -> Result<()> {
+    let input = read_input(&input.unwrap_or_else(|| default_input(Day10::DAY)))?;
+    let (map, pipe_loop) = Day10::parse(&input)?;
+    let colour = match colour {
+        ColourArg::Auto => output.is_none() && std::io::stdout().is_terminal(),
+        ColourArg::Always => true,
+        ColourArg::Never => false,
+    };
+    write_output(output, &map.render(&pipe_loop, colour))
+}
+
+fn pipe_components(anchor: Option<Pos>, input: Option<PathBuf>) -> Result<()> {
+    let input = read_input(&input.unwrap_or_else(|| default_input(Day10::DAY)))?;
+    let (map, start_error) = day10::parse_map(&input)?;
+    if let Some(error) = start_error {
+        eprintln!("Leaving the start tile as ground: {}", error);
+    }
+    let components = match anchor {
+        Some(anchor) => vec![map.component_at(anchor)?],
+        None => map.components(),
+    };
+
+    println!(
+        "{:>12}  {:<8}{:>8}{:>10}",
+        "anchor", "kind", "length", "enclosed"
+    );
+    for component in &components {
+        let (kind, enclosed) = match component.enclosed() {
+            Some(enclosed) => ("loop", enclosed.to_string()),
+            None => ("dangling", "-".to_string()),
+        };
+        let anchor = component.anchor();
+        println!(
+            "{:>12}  {:<8}{:>8}{:>10}",
+            format!("{},{}", anchor.x, anchor.y),
+            kind,
+            component.len(),
+            enclosed
+        );
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { days, part, input } => run(&days.0, part.parts(), input),
+        Command::CamelCards { rules, input } => camel_cards(&rules.rules(), input),
+        Command::NetworkDot {
+            starts,
+            ends,
+            input,
+            output,
+        } => network_dot(&starts, &ends, input, output),
+        Command::RenderPipes {
+            colour,
+            input,
+            output,
+        } => render_pipes(colour, input, output),
+        Command::PipeComponents { anchor, input } => pipe_components(anchor, input),
+    }
+}
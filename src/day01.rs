@@ -0,0 +1,112 @@
+use anyhow::{anyhow, Result};
+
+use crate::Solver;
+
+fn solve(input: &str) -> Result<u32> {
+    let result = input.lines().try_fold(0, |acc, line| {
+        let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+        let first = digits
+            .clone()
+            .next()
+            .ok_or_else(|| anyhow!("Line does not contain any digits"))?;
+        let last = digits.next_back().unwrap();
+        Ok::<_, anyhow::Error>(acc + first * 10 + last)
+    })?;
+
+    Ok(result)
+}
+
+fn fixup_input(input: &str) -> String {
+    let mut output = String::new();
+    let spellings = [
+        ("one", '1'),
+        ("two", '2'),
+        ("three", '3'),
+        ("four", '4'),
+        ("five", '5'),
+        ("six", '6'),
+        ("seven", '7'),
+        ("eight", '8'),
+        ("nine", '9'),
+    ];
+    for line in input.lines() {
+        for (i, c) in line.char_indices() {
+            for (spelling, val) in &spellings {
+                if line[i..].starts_with(spelling) {
+                    output.push(*val);
+                } else if c == *val {
+                    output.push(c)
+                }
+            }
+        }
+        output.push('\n');
+    }
+    output
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u32 = 1;
+
+    type Input = String;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.to_string())
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        solve(input)
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        solve(&fixup_input(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_A: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+    const EXAMPLE_B: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day01::parse(EXAMPLE_A).unwrap();
+        assert_eq!(Day01::part_a(&input).unwrap(), 142);
+    }
+
+    #[test]
+    fn part_b_example() {
+        let input = Day01::parse(EXAMPLE_B).unwrap();
+        assert_eq!(Day01::part_b(&input).unwrap(), 281);
+    }
+
+    #[test]
+    fn part_b_overlapping_spellings() {
+        let input = Day01::parse("eightwo\noneight\n").unwrap();
+        assert_eq!(Day01::part_b(&input).unwrap(), 82 + 18);
+    }
+
+    #[test]
+    fn line_without_digits() {
+        let input = Day01::parse("abc\n").unwrap();
+        assert!(Day01::part_a(&input).is_err());
+    }
+}
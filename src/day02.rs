@@ -1,12 +1,16 @@
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
     branch::alt,
-    bytes::complete::tag,
-    character::complete::{digit1, newline},
-    combinator::{map, map_res},
+    character::complete::digit1,
+    combinator::{cut, map, map_res},
+    error::context,
     multi::separated_list1,
     sequence::{delimited, separated_pair, tuple},
-    IResult,
+};
+
+use crate::{
+    parse::{lines, parse_all, tag, IResult},
+    Solver,
 };
 
 #[derive(Debug)]
@@ -17,7 +21,7 @@ struct Set {
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: u32,
     sets: Vec<Set>,
 }
@@ -30,11 +34,14 @@ fn parse_set(input: &str) -> IResult<&str, Set> {
     map(
         separated_list1(
             tag(", "),
-            separated_pair(
+            cut(separated_pair(
                 parse_u32,
                 tag(" "),
-                alt((tag("red"), tag("green"), tag("blue"))),
-            ),
+                context(
+                    "red|green|blue",
+                    alt((tag("red"), tag("green"), tag("blue"))),
+                ),
+            )),
         ),
         |cubes: Vec<(u32, &str)>| {
             let mut set = Set {
@@ -56,16 +63,13 @@ fn parse_set(input: &str) -> IResult<&str, Set> {
 }
 
 fn parse_games(input: &str) -> IResult<&str, Vec<Game>> {
-    separated_list1(
-        newline,
-        map(
-            tuple((
-                delimited(tag("Game "), parse_u32, tag(": ")),
-                separated_list1(tag("; "), parse_set),
-            )),
-            |(id, sets)| Game { id, sets },
-        ),
-    )(input)
+    lines(map(
+        tuple((
+            delimited(tag("Game "), parse_u32, tag(": ")),
+            separated_list1(tag("; "), cut(parse_set)),
+        )),
+        |(id, sets)| Game { id, sets },
+    ))(input)
 }
 
 fn part_a(games: &[Game]) -> u32 {
@@ -102,16 +106,48 @@ fn part_b(games: &[Game]) -> u32 {
         .sum()
 }
 
-fn main() -> Result<()> {
-    let input = std::fs::read_to_string("res/input02.txt")?;
-    let games = parse_games(&input)
-        .map_err(|e| anyhow!("Error parsing input: {:?}", e))?
-        .1;
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Game>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_games)?)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        Ok(part_a(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        Ok(part_b(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
 
-    let part_a = part_a(&games);
-    println!("Day 02, part A: {}", part_a);
+    #[test]
+    fn part_a_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part_a(&input).unwrap(), 8);
+    }
 
-    let part_b = part_b(&games);
-    println!("Day 02, part B: {}", part_b);
-    Ok(())
+    #[test]
+    fn part_b_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part_b(&input).unwrap(), 2286);
+    }
 }
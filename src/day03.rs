@@ -0,0 +1,140 @@
+use anyhow::Result;
+
+use crate::{
+    grid::{Grid, Pos},
+    Solver,
+};
+
+pub struct Part {
+    pos: Pos,
+}
+
+pub struct Number {
+    num: u32,
+    digits: usize,
+    pos: Pos,
+}
+
+impl Number {
+    fn adjacent_to(&self, part: &Part) -> bool {
+        part.pos.x >= self.pos.x - 1
+            && part.pos.x <= self.pos.x + self.digits as isize
+            && part.pos.y >= self.pos.y - 1
+            && part.pos.y <= self.pos.y + 1
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<Part>, Vec<Number>)> {
+    let grid = Grid::parse(input, |_pos, c| Ok(c))?;
+
+    let mut parts = Vec::new();
+    let mut numbers = Vec::new();
+
+    for (y, row) in grid.rows().enumerate() {
+        let mut x = 0;
+        while x < row.len() {
+            let pos = Pos::new(x as isize, y as isize);
+            match row[x] {
+                '.' => {}
+                c if c.is_ascii_digit() => {
+                    let digits = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+                    let num = row[x..x + digits]
+                        .iter()
+                        .fold(0, |acc, c| acc * 10 + c.to_digit(10).unwrap());
+                    numbers.push(Number { num, digits, pos });
+                    x += digits - 1;
+                }
+                _ => parts.push(Part { pos }),
+            }
+            x += 1
+        }
+    }
+
+    Ok((parts, numbers))
+}
+
+fn part_a(parts: &[Part], numbers: &[Number]) -> u32 {
+    numbers
+        .iter()
+        .filter_map(|number| {
+            if parts.iter().any(|part| number.adjacent_to(part)) {
+                Some(number.num)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn part_b(parts: &[Part], numbers: &[Number]) -> u32 {
+    parts
+        .iter()
+        .filter_map(|part| {
+            let adjacent_nums = numbers
+                .iter()
+                .filter_map(|number| number.adjacent_to(part).then_some(number.num));
+            if adjacent_nums.clone().count() == 2 {
+                Some(adjacent_nums.product::<u32>())
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u32 = 3;
+
+    type Input = (Vec<Part>, Vec<Number>);
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        Ok(part_a(&input.0, &input.1))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        Ok(part_b(&input.0, &input.1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part_a(&input).unwrap(), 4361);
+    }
+
+    #[test]
+    fn part_b_example() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part_b(&input).unwrap(), 467835);
+    }
+
+    #[test]
+    fn number_at_end_of_line() {
+        let input = Day03::parse("..12\n.*..\n").unwrap();
+        assert_eq!(Day03::part_a(&input).unwrap(), 12);
+    }
+}
@@ -0,0 +1,117 @@
+use anyhow::Result;
+use nom::{
+    character::complete::{digit1, space1},
+    combinator::{map, map_res},
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+};
+
+use crate::{
+    parse::{lines, parse_all, tag, IResult},
+    Solver,
+};
+
+#[derive(Clone)]
+pub struct Card {
+    count: usize,
+    winning_numbers: Vec<usize>,
+    numbers: Vec<usize>,
+}
+
+impl Card {
+    fn num_matching(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|num| self.winning_numbers.contains(num))
+            .count()
+    }
+
+    fn score(&self) -> usize {
+        match self.num_matching() {
+            0 => 0,
+            num => 2usize.pow(num as u32 - 1),
+        }
+    }
+}
+
+fn parse_usize(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, |s: &str| s.parse::<usize>())(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, Vec<Card>> {
+    lines(preceded(
+        tuple((tag("Card"), space1, digit1, tag(":"), space1)),
+        map(
+            separated_pair(
+                separated_list1(space1, parse_usize),
+                tuple((space1, tag("|"), space1)),
+                separated_list1(space1, parse_usize),
+            ),
+            |(winning_numbers, numbers)| Card {
+                count: 1,
+                winning_numbers,
+                numbers,
+            },
+        ),
+    ))(input)
+}
+
+fn part_a(cards: &[Card]) -> usize {
+    cards.iter().map(Card::score).sum()
+}
+
+fn part_b(mut cards: Vec<Card>) -> usize {
+    for i in 0..cards.len() {
+        for j in (i + 1)..=(i + cards[i].num_matching().min(cards.len() - 1)) {
+            cards[j].count += cards[i].count;
+        }
+    }
+    cards.iter().map(|card| card.count).sum()
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<Card>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        Ok(part_a(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        Ok(part_b(input.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part_a(&input).unwrap(), 13);
+    }
+
+    #[test]
+    fn part_b_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part_b(&input).unwrap(), 30);
+    }
+}
@@ -0,0 +1,719 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Range,
+};
+
+use anyhow::{anyhow, ensure, Result};
+use itertools::Itertools;
+use nom::{
+    character::complete::{alpha1, digit1, newline, space1},
+    combinator::{cut, map, map_res},
+    error::context,
+    multi::{many1, separated_list1},
+    sequence::{preceded, separated_pair, terminated, tuple},
+};
+
+use crate::{
+    parse::{lines, parse_all, tag, IResult},
+    Solver,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Mapping {
+    pub dest: usize,
+    pub source: usize,
+    pub len: usize,
+}
+
+impl Mapping {
+    fn source_range(&self) -> Range<usize> {
+        self.source..(self.source + self.len)
+    }
+}
+
+/// A problem with the source ranges of a map's mappings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapIssue {
+    /// Ids covered by more than one mapping. Only the first of these mappings is used.
+    Overlap(Range<usize>),
+    /// Ids between two mappings which aren't covered by either, and so map to themselves.
+    Gap(Range<usize>),
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    source: String,
+    dest: String,
+    mappings: Vec<Mapping>,
+}
+
+// Sorts the ranges and joins any which overlap or touch.
+fn merge_ranges(mut ranges: Vec<Range<usize>>) -> Vec<Range<usize>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+impl Map {
+    pub fn lookup(&self, id: usize) -> usize {
+        self.mappings
+            .iter()
+            .find_map(|mapping| {
+                (mapping.source..(mapping.source + mapping.len))
+                    .contains(&id)
+                    .then(|| mapping.dest + id - mapping.source)
+            })
+            .unwrap_or(id)
+    }
+
+    // Splits each of the half-open ranges of ids wherever they only partially overlap a mapping.
+    // Returns each piece along with the id its start maps to, or None if no mapping covers it.
+    fn split(&self, ranges: &[Range<usize>]) -> Vec<(Range<usize>, Option<usize>)> {
+        let mut unmapped = ranges.to_vec();
+        let mut pieces = Vec::new();
+
+        for mapping in &self.mappings {
+            let source = mapping.source_range();
+            let mut remaining = Vec::new();
+            for range in unmapped {
+                let start = range.start.max(source.start);
+                let end = range.end.min(source.end);
+                if start < end {
+                    pieces.push((start..end, Some(mapping.dest + start - mapping.source)));
+                    if range.start < start {
+                        remaining.push(range.start..start);
+                    }
+                    if end < range.end {
+                        remaining.push(end..range.end);
+                    }
+                } else {
+                    remaining.push(range);
+                }
+            }
+            unmapped = remaining;
+        }
+
+        pieces.extend(unmapped.into_iter().map(|range| (range, None)));
+        pieces
+    }
+
+    // The mappings which actually take effect, with the parts of any overlapping mappings which
+    // are hidden by earlier ones removed.
+    fn effective_mappings(&self) -> Vec<Mapping> {
+        let sources = self
+            .mappings
+            .iter()
+            .map(Mapping::source_range)
+            .collect_vec();
+        self.split(&merge_ranges(sources))
+            .into_iter()
+            .filter_map(|(range, dest)| {
+                dest.map(|dest| Mapping {
+                    dest,
+                    source: range.start,
+                    len: range.len(),
+                })
+            })
+            .collect()
+    }
+
+    /// Maps each of the half-open ranges of ids, splitting them wherever they only partially
+    /// overlap a mapping. Ids not covered by any mapping are passed through unchanged.
+    pub fn lookup_ranges(&self, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        self.split(ranges)
+            .into_iter()
+            .map(|(range, dest)| match dest {
+                Some(dest) => dest..(dest + range.len()),
+                None => range,
+            })
+            .collect()
+    }
+
+    /// Finds every id which maps into one of the ranges.
+    pub fn preimage_ranges(&self, ranges: &[Range<usize>]) -> Vec<Range<usize>> {
+        let mut preimage = Vec::new();
+        for mapping in self.effective_mappings() {
+            for range in ranges {
+                let start = range.start.max(mapping.dest);
+                let end = range.end.min(mapping.dest + mapping.len);
+                if start < end {
+                    preimage.push(
+                        (mapping.source + start - mapping.dest)
+                            ..(mapping.source + end - mapping.dest),
+                    );
+                }
+            }
+        }
+        // Ids not covered by any mapping map to themselves.
+        preimage.extend(
+            self.split(ranges)
+                .into_iter()
+                .filter_map(|(range, dest)| dest.is_none().then_some(range)),
+        );
+        merge_ranges(preimage)
+    }
+
+    /// Combines this map with one from this map's destination category, into a single map which
+    /// gives the same results as looking an id up in each in turn.
+    pub fn compose(&self, next: &Map) -> Result<Map> {
+        ensure!(
+            self.dest == next.source,
+            "Cannot compose {}-to-{} map with {}-to-{} map",
+            self.source,
+            self.dest,
+            next.source,
+            next.dest
+        );
+
+        let mut mappings = Vec::new();
+        // Ids which this map moves are then looked up in the next map.
+        for mapping in self.effective_mappings() {
+            let dests = mapping.dest..(mapping.dest + mapping.len);
+            for (range, dest) in next.split(std::slice::from_ref(&dests)) {
+                mappings.push(Mapping {
+                    dest: dest.unwrap_or(range.start),
+                    source: mapping.source + range.start - mapping.dest,
+                    len: range.len(),
+                });
+            }
+        }
+        // Ids which this map leaves unchanged are only moved by the next map.
+        let next_sources = next
+            .mappings
+            .iter()
+            .map(Mapping::source_range)
+            .collect_vec();
+        for (range, dest) in self.split(&merge_ranges(next_sources)) {
+            if dest.is_none() {
+                mappings.extend(
+                    next.split(&[range])
+                        .into_iter()
+                        .filter_map(|(range, dest)| {
+                            dest.map(|dest| Mapping {
+                                dest,
+                                source: range.start,
+                                len: range.len(),
+                            })
+                        }),
+                );
+            }
+        }
+        mappings.retain(|mapping| mapping.source != mapping.dest);
+        mappings.sort();
+
+        Ok(Map {
+            source: self.source.clone(),
+            dest: next.dest.clone(),
+            mappings,
+        })
+    }
+
+    /// Creates the map in the opposite direction. Only possible if every id is mapped to by
+    /// exactly one id.
+    pub fn invert(&self) -> Result<Map> {
+        let mappings = self.effective_mappings();
+        let sources = merge_ranges(mappings.iter().map(Mapping::source_range).collect());
+        let mut dests = mappings
+            .iter()
+            .map(|mapping| mapping.dest..(mapping.dest + mapping.len))
+            .collect_vec();
+        dests.sort_by_key(|range| range.start);
+        let dests_overlap = dests.iter().tuple_windows().any(|(a, b)| b.start < a.end);
+        ensure!(
+            !dests_overlap && merge_ranges(dests) == sources,
+            "{}-to-{} map is not invertible",
+            self.source,
+            self.dest
+        );
+
+        Ok(Map {
+            source: self.dest.clone(),
+            dest: self.source.clone(),
+            mappings: mappings
+                .into_iter()
+                .map(|mapping| Mapping {
+                    dest: mapping.source,
+                    source: mapping.dest,
+                    len: mapping.len,
+                })
+                .collect(),
+        })
+    }
+
+    /// Finds any overlaps between, or gaps between, the mappings' source ranges.
+    pub fn validate(&self) -> Vec<MapIssue> {
+        let mut sources = self
+            .mappings
+            .iter()
+            .map(Mapping::source_range)
+            .collect_vec();
+        sources.sort_by_key(|range| range.start);
+
+        let mut issues = Vec::new();
+        let mut covered_end = None;
+        for range in sources {
+            match covered_end {
+                Some(end) if range.start < end => {
+                    issues.push(MapIssue::Overlap(range.start..range.end.min(end)))
+                }
+                Some(end) if range.start > end => issues.push(MapIssue::Gap(end..range.start)),
+                _ => {}
+            }
+            covered_end = Some(covered_end.map_or(range.end, |end: usize| end.max(range.end)));
+        }
+        issues
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<Map>,
+    // Indices of the maps from each source category.
+    maps_from: HashMap<String, Vec<usize>>,
+}
+
+impl Almanac {
+    fn new(seeds: Vec<usize>, maps: Vec<Map>) -> Self {
+        let mut maps_from: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, map) in maps.iter().enumerate() {
+            maps_from.entry(map.source.clone()).or_default().push(i);
+        }
+        Self {
+            seeds,
+            maps,
+            maps_from,
+        }
+    }
+
+    // Finds the shortest chain of maps which converts from one category to another.
+    fn path(&self, from: &str, to: &str) -> Result<Vec<&Map>> {
+        let mut prev: HashMap<&str, Option<&Map>> = HashMap::from([(from, None)]);
+        let mut open = VecDeque::from([from]);
+
+        while let Some(category) = open.pop_front() {
+            if category == to {
+                let mut path = Vec::new();
+                let mut category = to;
+                while let Some(map) = prev[category] {
+                    path.push(map);
+                    category = &map.source;
+                }
+                path.reverse();
+                return Ok(path);
+            }
+
+            for map in self.maps_from.get(category).into_iter().flatten() {
+                let map = &self.maps[*map];
+                if !prev.contains_key(map.dest.as_str()) {
+                    prev.insert(&map.dest, Some(map));
+                    open.push_back(&map.dest);
+                }
+            }
+        }
+
+        Err(anyhow!("No maps from {:?} to {:?}", from, to))
+    }
+
+    pub fn convert(&self, value: usize, from: &str, to: &str) -> Result<usize> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .fold(value, |value, map| map.lookup(value)))
+    }
+
+    pub fn convert_ranges(
+        &self,
+        ranges: &[Range<usize>],
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Range<usize>>> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .fold(ranges.to_vec(), |ranges, map| map.lookup_ranges(&ranges)))
+    }
+
+    /// Finds every value in the `from` category which converts into one of the ranges of the
+    /// `to` category.
+    pub fn preimage_ranges(
+        &self,
+        ranges: &[Range<usize>],
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Range<usize>>> {
+        Ok(self
+            .path(from, to)?
+            .iter()
+            .rev()
+            .fold(ranges.to_vec(), |ranges, map| map.preimage_ranges(&ranges)))
+    }
+
+    /// Collapses the chain of maps between two categories into a single equivalent map.
+    pub fn compose_path(&self, from: &str, to: &str) -> Result<Map> {
+        let identity = Map {
+            source: from.to_string(),
+            dest: from.to_string(),
+            mappings: Vec::new(),
+        };
+        self.path(from, to)?
+            .into_iter()
+            .try_fold(identity, |composed, map| composed.compose(map))
+    }
+}
+
+fn parse_usize(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, |s: &str| s.parse::<usize>())(input)
+}
+
+pub fn parse_seeds(input: &str) -> IResult<&str, Vec<usize>> {
+    preceded(tag("seeds: "), separated_list1(space1, parse_usize))(input)
+}
+
+fn parse_mapping(input: &str) -> IResult<&str, Mapping> {
+    map(
+        tuple((
+            terminated(parse_usize, space1),
+            terminated(parse_usize, space1),
+            parse_usize,
+        )),
+        |(dest, source, len)| Mapping { dest, source, len },
+    )(input)
+}
+
+fn parse_category(input: &str) -> IResult<&str, &str> {
+    context("a-z", alpha1)(input)
+}
+
+fn parse_map(input: &str) -> IResult<&str, Map> {
+    map(
+        separated_pair(
+            terminated(
+                separated_pair(parse_category, tag("-to-"), parse_category),
+                tag(" map:"),
+            ),
+            newline,
+            lines(parse_mapping),
+        ),
+        |((source, dest), mappings)| Map {
+            source: source.to_string(),
+            dest: dest.to_string(),
+            mappings,
+        },
+    )(input)
+}
+
+fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
+    map(
+        // Only a map can follow a blank line, so a malformed one is reported where it goes wrong.
+        tuple((parse_seeds, many1(preceded(many1(newline), cut(parse_map))))),
+        |(seeds, maps)| Almanac::new(seeds, maps),
+    )(input)
+}
+
+fn part_a(almanac: &Almanac) -> Result<usize> {
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| almanac.convert(*seed, "seed", "location"))
+        .process_results(|locations| locations.min())?
+        .ok_or_else(|| anyhow!("No seeds"))
+}
+
+fn part_b(almanac: &Almanac) -> Result<usize> {
+    let seeds = almanac
+        .seeds
+        .chunks_exact(2)
+        .map(|seeds| {
+            let start = seeds[0];
+            let len = seeds[1];
+            start..(start + len)
+        })
+        .collect::<Vec<_>>();
+
+    almanac
+        .convert_ranges(&seeds, "seed", "location")?
+        .iter()
+        .filter(|range| !range.is_empty())
+        .map(|range| range.start)
+        .min()
+        .ok_or_else(|| anyhow!("No seeds"))
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = Almanac;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_almanac)?)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        part_b(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::ParseError;
+
+    const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part_a(&input).unwrap(), 35);
+    }
+
+    #[test]
+    fn part_b_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part_b(&input).unwrap(), 46);
+    }
+
+    #[test]
+    fn lookup_ranges() {
+        let map = Map {
+            source: "a".to_string(),
+            dest: "b".to_string(),
+            mappings: vec![
+                Mapping {
+                    dest: 100,
+                    source: 10,
+                    len: 5,
+                },
+                Mapping {
+                    dest: 50,
+                    source: 15,
+                    len: 5,
+                },
+            ],
+        };
+
+        let mut ranges = map.lookup_ranges(&[0..5, 8..17, 19..25]);
+        ranges.sort_by_key(|range| range.start);
+        assert_eq!(ranges, [0..5, 8..10, 20..25, 50..52, 54..55, 100..105]);
+
+        // Every id in a range maps to the same place as looking it up individually.
+        let ranges = map.lookup_ranges(&[0..12, 12..30]);
+        for id in 0..30 {
+            let location = map.lookup(id);
+            assert_eq!(
+                ranges
+                    .iter()
+                    .filter(|range| range.contains(&location))
+                    .count(),
+                1
+            );
+        }
+        assert_eq!(ranges.iter().map(|range| range.len()).sum::<usize>(), 30);
+    }
+
+    const VARIANT: &str = "\
+seeds: 1 10 40
+
+soil-to-location map:
+100 0 50
+
+seed-to-soil map:
+10 0 20
+
+location-to-region map:
+0 100 5
+";
+
+    #[test]
+    fn reordered_and_extra_categories() {
+        let almanac = Day05::parse(VARIANT).unwrap();
+        assert_eq!(almanac.convert(1, "seed", "soil").unwrap(), 11);
+        assert_eq!(almanac.convert(1, "seed", "location").unwrap(), 111);
+        assert_eq!(almanac.convert(40, "seed", "location").unwrap(), 140);
+        assert_eq!(almanac.convert(102, "location", "region").unwrap(), 2);
+        assert_eq!(almanac.convert(5, "soil", "region").unwrap(), 105);
+        assert_eq!(almanac.convert(5, "seed", "seed").unwrap(), 5);
+        assert!(almanac.convert(5, "location", "seed").is_err());
+        assert!(almanac.convert(5, "seed", "humidity").is_err());
+
+        assert_eq!(Day05::part_a(&almanac).unwrap(), 111);
+        assert_eq!(
+            almanac
+                .convert_ranges(&[0..5, 90..95], "seed", "region")
+                .unwrap(),
+            [110..115, 90..95]
+        );
+    }
+
+    #[test]
+    fn parse_error_location() {
+        let located = |input: &str| {
+            let Err(err) = Day05::parse(input) else {
+                panic!("{:?} should fail to parse", input);
+            };
+            let err = err.downcast_ref::<ParseError>().unwrap();
+            (err.line, err.column, err.expected.clone())
+        };
+
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50 x\n";
+        assert_eq!(located(input), (5, 7, "a number".to_string()));
+
+        let input = "\
+seeds: 79 14
+
+seed-to-soil map:
+50 98 2
+
+soil-to-location mapp:
+0 15 37
+";
+        assert_eq!(located(input), (6, 17, "` map:`".to_string()));
+
+        let input = "seeds: 79 14\n\nseed-to- map:\n50 98 2\n";
+        assert_eq!(located(input), (3, 9, "`a-z`".to_string()));
+    }
+
+    #[test]
+    fn compose_path() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let composed = almanac.compose_path("seed", "location").unwrap();
+        assert_eq!(
+            (composed.source.as_str(), composed.dest.as_str()),
+            ("seed", "location")
+        );
+        for seed in 0..150 {
+            assert_eq!(
+                composed.lookup(seed),
+                almanac.convert(seed, "seed", "location").unwrap()
+            );
+        }
+
+        let soil = &almanac.maps[almanac.maps_from["soil"][0]];
+        assert!(composed.compose(soil).is_err());
+    }
+
+    #[test]
+    fn invert() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let composed = almanac.compose_path("seed", "location").unwrap();
+        let inverse = composed.invert().unwrap();
+        assert_eq!(
+            (inverse.source.as_str(), inverse.dest.as_str()),
+            ("location", "seed")
+        );
+        for seed in 0..150 {
+            assert_eq!(inverse.lookup(composed.lookup(seed)), seed);
+        }
+
+        let map = Map {
+            source: "a".to_string(),
+            dest: "b".to_string(),
+            mappings: vec![Mapping {
+                dest: 0,
+                source: 10,
+                len: 5,
+            }],
+        };
+        assert!(map.invert().is_err());
+    }
+
+    #[test]
+    fn preimage_ranges() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        let locations = [40..50, 50..60];
+        let seeds = almanac
+            .preimage_ranges(&locations, "seed", "location")
+            .unwrap();
+        let expected = (0..150).filter(|seed| {
+            let location = almanac.convert(*seed, "seed", "location").unwrap();
+            (40..60).contains(&location)
+        });
+        assert!(seeds.into_iter().flatten().sorted().eq(expected));
+
+        // The seed with the lowest location in part B.
+        let locations = 46..47;
+        let seeds = almanac
+            .preimage_ranges(std::slice::from_ref(&locations), "seed", "location")
+            .unwrap();
+        assert!(seeds.iter().any(|range| range.contains(&82)));
+    }
+
+    #[test]
+    fn validate() {
+        let almanac = Day05::parse(EXAMPLE).unwrap();
+        assert!(almanac.maps.iter().all(|map| map.validate().is_empty()));
+
+        let map = Map {
+            source: "a".to_string(),
+            dest: "b".to_string(),
+            mappings: vec![
+                Mapping {
+                    dest: 100,
+                    source: 0,
+                    len: 10,
+                },
+                Mapping {
+                    dest: 200,
+                    source: 5,
+                    len: 10,
+                },
+                Mapping {
+                    dest: 300,
+                    source: 20,
+                    len: 10,
+                },
+            ],
+        };
+        assert_eq!(
+            map.validate(),
+            [MapIssue::Overlap(5..10), MapIssue::Gap(15..20)]
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{bail, Context, Result};
+use nom::{
+    character::complete::{digit1, newline, space1},
+    combinator::map,
+    multi::separated_list1,
+    sequence::{preceded, separated_pair, tuple},
+};
+
+use crate::{
+    parse::{parse_all, tag, IResult},
+    Solver,
+};
+
+#[derive(Debug)]
+pub struct Race {
+    time: u128,
+    distance: u128,
+}
+
+impl Race {
+    /// The range of hold times which beat the record distance, or `None` if the record can't be
+    /// beaten.
+    ///
+    /// Holding for `h` travels `h * (time - h)`, which is symmetric about `time / 2`. Writing
+    /// `time = 2m + r` and `h = m - k`, the distance is `m(m + r) - k(k + r)`, so the winning
+    /// holds are those with `k(k + r) < m(m + r) - distance`, and the largest such `k` is found
+    /// with an exact integer square root.
+    pub fn winning_holds(&self) -> Result<Option<RangeInclusive<u128>>> {
+        let (m, r) = (self.time / 2, self.time % 2);
+        let best = m
+            .checked_mul(m + r)
+            .with_context(|| format!("Race time {} is too large", self.time))?;
+        if best <= self.distance {
+            return Ok(None);
+        }
+
+        let slack = best - self.distance - 1;
+        let mut k = slack.isqrt();
+        // k * k <= slack, but with an odd time we need k * (k + 1) <= slack.
+        if k * (k + r) > slack {
+            k -= 1;
+        }
+        Ok(Some(m - k..=m + r + k))
+    }
+
+    /// The number of hold times which beat the record distance.
+    pub fn ways_to_win(&self) -> Result<u128> {
+        Ok(self
+            .winning_holds()?
+            .map_or(0, |holds| holds.end() - holds.start() + 1))
+    }
+}
+
+/// How the numbers on each line of the sheet should be read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kerning {
+    /// Each number is a separate race.
+    Spaced,
+    /// The spaces are bad kerning, and the digits on each line form a single race.
+    Kerned,
+}
+
+/// The digit tokens of the `Time:` and `Distance:` lines, kept as text so that they can be read
+/// either spaced or kerned.
+#[derive(Debug)]
+pub struct Sheet {
+    times: Vec<String>,
+    distances: Vec<String>,
+}
+
+fn parse_number(token: &str, what: &str) -> Result<u128> {
+    token
+        .parse()
+        .with_context(|| format!("{} {} doesn't fit in 128 bits", what, token))
+}
+
+impl Sheet {
+    pub fn races(&self, kerning: Kerning) -> Result<Vec<Race>> {
+        match kerning {
+            Kerning::Spaced => self
+                .times
+                .iter()
+                .zip(&self.distances)
+                .map(|(time, distance)| {
+                    Ok(Race {
+                        time: parse_number(time, "Time")?,
+                        distance: parse_number(distance, "Distance")?,
+                    })
+                })
+                .collect(),
+            Kerning::Kerned => Ok(vec![Race {
+                time: parse_number(&self.times.concat(), "Kerned time")?,
+                distance: parse_number(&self.distances.concat(), "Kerned distance")?,
+            }]),
+        }
+    }
+}
+
+fn parse_tokens(input: &str) -> IResult<&str, Vec<String>> {
+    separated_list1(space1, map(digit1, str::to_string))(input)
+}
+
+fn parse_sheet(input: &str) -> IResult<&str, Sheet> {
+    map(
+        separated_pair(
+            preceded(tuple((tag("Time:"), space1)), parse_tokens),
+            newline,
+            preceded(tuple((tag("Distance:"), space1)), parse_tokens),
+        ),
+        |(times, distances)| Sheet { times, distances },
+    )(input)
+}
+
+fn part_a(races: &[Race]) -> Result<u128> {
+    races.iter().try_fold(1u128, |acc, race| {
+        acc.checked_mul(race.ways_to_win()?)
+            .context("Product of ways to win overflowed")
+    })
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u32 = 6;
+
+    type Input = Sheet;
+    type Output = u128;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let sheet = parse_all(input, parse_sheet)?;
+        if sheet.times.len() != sheet.distances.len() {
+            bail!(
+                "{} times but {} distances",
+                sheet.times.len(),
+                sheet.distances.len()
+            );
+        }
+        Ok(sheet)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        part_a(&input.races(Kerning::Spaced)?)
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        part_a(&input.races(Kerning::Kerned)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part_a(&input).unwrap(), 288);
+    }
+
+    #[test]
+    fn part_b_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part_b(&input).unwrap(), 71503);
+    }
+
+    #[test]
+    fn kerning() {
+        let sheet = Day06::parse("Time: 1 00\nDistance: 9 0 0\n");
+        assert!(sheet.is_err());
+
+        let sheet = Day06::parse("Time: 1 00\nDistance: 9 00\n").unwrap();
+        let races = sheet.races(Kerning::Kerned).unwrap();
+        assert_eq!((races[0].time, races[0].distance), (100, 900));
+        let races = sheet.races(Kerning::Spaced).unwrap();
+        assert_eq!((races[1].time, races[1].distance), (0, 0));
+
+        let digits = "9".repeat(38);
+        let sheet = Day06::parse(&format!("Time: {} 9\nDistance: 1 1\n", digits)).unwrap();
+        assert!(sheet.races(Kerning::Spaced).is_ok());
+        let error = sheet.races(Kerning::Kerned).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!("Kerned time {}9 doesn't fit in 128 bits", digits)
+        );
+    }
+
+    #[test]
+    fn winning_holds() {
+        let race = |time, distance| Race { time, distance };
+        assert_eq!(race(7, 9).winning_holds().unwrap(), Some(2..=5));
+        assert_eq!(race(15, 40).winning_holds().unwrap(), Some(4..=11));
+        assert_eq!(race(30, 200).winning_holds().unwrap(), Some(11..=19));
+        // Only the exact midpoint ties the record, which doesn't count as a win.
+        assert_eq!(race(10, 25).winning_holds().unwrap(), None);
+        assert_eq!(race(10, 24).winning_holds().unwrap(), Some(5..=5));
+        assert_eq!(race(0, 0).winning_holds().unwrap(), None);
+    }
+
+    #[test]
+    fn ways_to_win_matches_brute_force() {
+        for time in 0..60u128 {
+            for distance in 0..1000 {
+                let race = Race { time, distance };
+                let expected = (0..=time)
+                    .filter(|hold| hold * (time - hold) > distance)
+                    .count() as u128;
+                assert_eq!(race.ways_to_win().unwrap(), expected, "{:?}", race);
+            }
+        }
+    }
+
+    #[test]
+    fn beyond_u64() {
+        let time = u64::MAX as u128 * 2 + 1;
+        let distance = (time / 2) * (time / 2 + 1) - 12345678;
+        let race = Race { time, distance };
+        let holds = race.winning_holds().unwrap().unwrap();
+        let travelled = |hold: u128| hold * (time - hold);
+        assert!(travelled(*holds.start()) > distance);
+        assert!(travelled(holds.start() - 1) <= distance);
+        assert!(travelled(*holds.end()) > distance);
+        assert!(travelled(holds.end() + 1) <= distance);
+
+        let race = Race {
+            time: u128::MAX,
+            distance: 0,
+        };
+        assert!(race.winning_holds().is_err());
+    }
+}
@@ -0,0 +1,462 @@
+use std::{cmp::Reverse, fmt::Display};
+
+use anyhow::{anyhow, ensure, Error, Result};
+use nom::{
+    character::complete::{anychar, digit1, space1},
+    combinator::{map, map_res},
+    error::context,
+    multi::count,
+    sequence::separated_pair,
+};
+
+use crate::{
+    parse::{lines, parse_all, IResult},
+    Solver,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Card {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+const NUM_CARDS: usize = Card::Ace as usize + 1;
+
+impl Card {
+    const ALL: [Card; NUM_CARDS] = [
+        Card::Two,
+        Card::Three,
+        Card::Four,
+        Card::Five,
+        Card::Six,
+        Card::Seven,
+        Card::Eight,
+        Card::Nine,
+        Card::Ten,
+        Card::Jack,
+        Card::Queen,
+        Card::King,
+        Card::Ace,
+    ];
+}
+
+impl TryFrom<char> for Card {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '2' => Ok(Card::Two),
+            '3' => Ok(Card::Three),
+            '4' => Ok(Card::Four),
+            '5' => Ok(Card::Five),
+            '6' => Ok(Card::Six),
+            '7' => Ok(Card::Seven),
+            '8' => Ok(Card::Eight),
+            '9' => Ok(Card::Nine),
+            'T' => Ok(Card::Ten),
+            'J' => Ok(Card::Jack),
+            'Q' => Ok(Card::Queen),
+            'K' => Ok(Card::King),
+            'A' => Ok(Card::Ace),
+            _ => Err(anyhow!("Cannot convert {:?} into Card", value)),
+        }
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Card::Two => '2',
+            Card::Three => '3',
+            Card::Four => '4',
+            Card::Five => '5',
+            Card::Six => '6',
+            Card::Seven => '7',
+            Card::Eight => '8',
+            Card::Nine => '9',
+            Card::Ten => 'T',
+            Card::Jack => 'J',
+            Card::Queen => 'Q',
+            Card::King => 'K',
+            Card::Ace => 'A',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Classifies a hand from its card counts, sorted largest first.
+    fn from_counts(counts: &[usize]) -> Self {
+        match (counts[0], counts.get(1).copied().unwrap_or(0)) {
+            (5, _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// Why a hand has the type it does under a set of rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub hand_type: HandType,
+    /// The position of each wild card in the hand and the card it stands in for.
+    pub substitutions: Vec<(usize, Card)>,
+    /// The hand after substitution.
+    pub cards: [Card; 5],
+    /// How many of each card the hand holds after substitution, largest group first.
+    pub histogram: Vec<(Card, usize)>,
+}
+
+/// How hands of the same type are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Compare the first card dealt, then the second, and so on.
+    Dealt,
+    /// Compare the strongest card in each hand, then the next strongest, and so on.
+    Strongest,
+}
+
+/// A variant of Camel Cards: the strength of each card, which cards are wild and how ties between
+/// hands of the same type are broken.
+#[derive(Debug, Clone)]
+pub struct Rules {
+    strength: [usize; NUM_CARDS],
+    wild: Vec<Card>,
+    tie_break: TieBreak,
+}
+
+fn parse_card_list(cards: &str) -> Result<Vec<Card>> {
+    cards.chars().map(Card::try_from).collect()
+}
+
+impl Rules {
+    /// Rules with no wild cards, where `order` lists every card from weakest to strongest, e.g.
+    /// `"A23456789TJQK"` for aces low.
+    pub fn new(order: &str) -> Result<Self> {
+        let order = parse_card_list(order)?;
+        ensure!(
+            order.len() == NUM_CARDS,
+            "Card order has {} cards, expected {}",
+            order.len(),
+            NUM_CARDS
+        );
+
+        let mut strength = [None; NUM_CARDS];
+        for (i, card) in order.into_iter().enumerate() {
+            ensure!(
+                strength[card as usize].replace(i).is_none(),
+                "{:?} appears twice in the card order",
+                card
+            );
+        }
+        Ok(Self {
+            strength: strength.map(Option::unwrap),
+            wild: Vec::new(),
+            tie_break: TieBreak::Dealt,
+        })
+    }
+
+    /// The rules for part A.
+    pub fn standard() -> Self {
+        Self::new("23456789TJQKA").unwrap()
+    }
+
+    /// The rules for part B, where jacks are jokers: wild, but the weakest card in a tie-break.
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA").unwrap().with_wild("J").unwrap()
+    }
+
+    /// Makes each of the given cards wild.
+    pub fn with_wild(mut self, cards: &str) -> Result<Self> {
+        self.wild = parse_card_list(cards)?;
+        Ok(self)
+    }
+
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    pub fn is_wild(&self, card: Card) -> bool {
+        self.wild.contains(&card)
+    }
+
+    pub fn hand_type(&self, cards: &[Card; 5]) -> HandType {
+        let mut card_counts = [0usize; NUM_CARDS];
+        let mut num_wild = 0;
+        for &card in cards {
+            if self.is_wild(card) {
+                num_wild += 1;
+            } else {
+                card_counts[card as usize] += 1;
+            }
+        }
+
+        // Wild cards are always best used to extend the largest group.
+        card_counts.sort_by(|a, b| b.cmp(a));
+        card_counts[0] += num_wild;
+
+        HandType::from_counts(&card_counts)
+    }
+
+    /// Works out the hand's type along with the card each wild card stands in for. Wild cards
+    /// join the largest group of other cards, preferring the strongest card when groups are tied,
+    /// and become the strongest card if the whole hand is wild.
+    pub fn explain(&self, hand: &Hand) -> Explanation {
+        let best = hand
+            .cards
+            .iter()
+            .filter(|card| !self.is_wild(**card))
+            .max_by_key(|card| {
+                let count = hand.cards.iter().filter(|other| other == card).count();
+                (count, self.strength[**card as usize])
+            })
+            .copied()
+            .unwrap_or_else(|| self.strongest());
+
+        let mut cards = hand.cards;
+        let mut substitutions = Vec::new();
+        for (i, card) in cards.iter_mut().enumerate() {
+            if self.is_wild(*card) {
+                *card = best;
+                substitutions.push((i, best));
+            }
+        }
+
+        let mut histogram = Vec::<(Card, usize)>::new();
+        for card in cards {
+            match histogram.iter_mut().find(|(other, _)| *other == card) {
+                Some((_, count)) => *count += 1,
+                None => histogram.push((card, 1)),
+            }
+        }
+        histogram.sort_by_key(|&(card, count)| Reverse((count, self.strength[card as usize])));
+
+        let counts = histogram
+            .iter()
+            .map(|(_, count)| *count)
+            .collect::<Vec<_>>();
+        Explanation {
+            hand_type: HandType::from_counts(&counts),
+            substitutions,
+            cards,
+            histogram,
+        }
+    }
+
+    /// The strongest card which isn't wild, or the strongest card if everything is wild.
+    fn strongest(&self) -> Card {
+        let strength = |card: &&Card| (!self.is_wild(**card), self.strength[**card as usize]);
+        *Card::ALL.iter().max_by_key(strength).unwrap()
+    }
+
+    fn sort_key(&self, hand: &Hand) -> (HandType, [usize; 5]) {
+        let mut strengths = hand.cards.map(|card| self.strength[card as usize]);
+        if self.tie_break == TieBreak::Strongest {
+            strengths.sort_by(|a, b| b.cmp(a));
+        }
+        (self.hand_type(&hand.cards), strengths)
+    }
+
+    /// Orders the hands from weakest to strongest.
+    pub fn rank<'a>(&self, hands: &'a [Hand]) -> Vec<&'a Hand> {
+        let mut ranked = hands.iter().collect::<Vec<_>>();
+        ranked.sort_by_cached_key(|hand| self.sort_key(hand));
+        ranked
+    }
+
+    /// The sum of each hand's bid multiplied by its rank.
+    pub fn total_winnings(&self, hands: &[Hand]) -> usize {
+        self.rank(hands)
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| (i + 1) * hand.bid)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hand {
+    cards: [Card; 5],
+    bid: usize,
+}
+
+impl Hand {
+    pub fn cards(&self) -> &[Card; 5] {
+        &self.cards
+    }
+
+    pub fn bid(&self) -> usize {
+        self.bid
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.cards.iter().try_for_each(|card| write!(f, "{}", card))
+    }
+}
+
+fn parse_usize(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, |s: &str| s.parse::<usize>())(input)
+}
+
+fn parse_cards(input: &str) -> IResult<&str, [Card; 5]> {
+    map(
+        count(
+            context("2-9|T|J|Q|K|A", map_res(anychar, Card::try_from)),
+            5,
+        ),
+        |cards| cards.try_into().unwrap(),
+    )(input)
+}
+
+fn parse_hands(input: &str) -> IResult<&str, Vec<Hand>> {
+    lines(map(
+        separated_pair(parse_cards, space1, parse_usize),
+        |(cards, bid)| Hand { cards, bid },
+    ))(input)
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Vec<Hand>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_hands)?)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        Ok(Rules::standard().total_winnings(input))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        Ok(Rules::jokers().total_winnings(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part_a(&input).unwrap(), 6440);
+    }
+
+    #[test]
+    fn part_b_example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part_b(&input).unwrap(), 5905);
+    }
+
+    fn hand(cards: &str) -> Hand {
+        Hand {
+            cards: parse_card_list(cards).unwrap().try_into().unwrap(),
+            bid: 0,
+        }
+    }
+
+    #[test]
+    fn wild_cards() {
+        let rules = Rules::standard().with_wild("J2").unwrap();
+        assert_eq!(
+            rules.hand_type(&hand("J2345").cards),
+            HandType::ThreeOfAKind
+        );
+        assert_eq!(rules.hand_type(&hand("J2J22").cards), HandType::FiveOfAKind);
+        assert_eq!(rules.hand_type(&hand("J2K3K").cards), HandType::FourOfAKind);
+        assert_eq!(rules.hand_type(&hand("J3344").cards), HandType::FullHouse);
+        assert_eq!(
+            Rules::standard().hand_type(&hand("J2345").cards),
+            HandType::HighCard
+        );
+    }
+
+    #[test]
+    fn house_variants() {
+        let hands = [hand("A2345"), hand("K2345")];
+        let strongest = |rules: &Rules| rules.rank(&hands)[1].clone();
+        assert_eq!(strongest(&Rules::standard()), hands[0]);
+        assert_eq!(strongest(&Rules::new("A23456789TJQK").unwrap()), hands[1]);
+
+        let hands = [hand("2345A"), hand("K2345")];
+        let strongest = |rules: &Rules| rules.rank(&hands)[1].clone();
+        assert_eq!(strongest(&Rules::standard()), hands[1]);
+        assert_eq!(
+            strongest(&Rules::standard().with_tie_break(TieBreak::Strongest)),
+            hands[0]
+        );
+
+        assert!(Rules::new("23456789TJQK").is_err());
+        assert!(Rules::new("223456789TJQK").is_err());
+    }
+
+    #[test]
+    fn explain() {
+        let rules = Rules::jokers();
+        let explanation = rules.explain(&hand("KTJJT"));
+        assert_eq!(explanation.hand_type, HandType::FourOfAKind);
+        assert_eq!(explanation.substitutions, [(2, Card::Ten), (3, Card::Ten)]);
+        assert_eq!(explanation.cards, hand("KTTTT").cards);
+        assert_eq!(explanation.histogram, [(Card::Ten, 4), (Card::King, 1)]);
+
+        // Ties between groups go to the stronger card.
+        let explanation = rules.explain(&hand("2JQ2Q"));
+        assert_eq!(explanation.substitutions, [(1, Card::Queen)]);
+        assert_eq!(explanation.hand_type, HandType::FullHouse);
+
+        let explanation = rules.explain(&hand("JJJJJ"));
+        assert_eq!(explanation.cards, hand("AAAAA").cards);
+
+        let explanation = Rules::standard().explain(&hand("32T3K"));
+        assert_eq!(explanation.hand_type, HandType::OnePair);
+        assert!(explanation.substitutions.is_empty());
+
+        let input = Day07::parse(EXAMPLE).unwrap();
+        for rules in [Rules::standard(), Rules::jokers()] {
+            for hand in &input {
+                assert_eq!(rules.explain(hand).hand_type, rules.hand_type(&hand.cards));
+            }
+        }
+    }
+}
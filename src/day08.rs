@@ -0,0 +1,662 @@
+use std::{collections::HashMap, fmt::Write};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use itertools::Itertools;
+use nom::{
+    character::complete::{alphanumeric1, newline, one_of},
+    combinator::map,
+    error::context,
+    multi::many1,
+    sequence::{pair, separated_pair, terminated, tuple},
+};
+
+use crate::{
+    parse::{lines, parse_all, tag, IResult},
+    Solver,
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// A node in a [`Network`], identified by its index.
+pub type Node = usize;
+
+/// The nodes of the map with their names interned, and the left and right neighbour of each node
+/// stored by index.
+#[derive(Debug, Clone)]
+pub struct Network {
+    names: Vec<String>,
+    ids: HashMap<String, Node>,
+    next: Vec<(Node, Node)>,
+}
+
+impl Network {
+    fn new(nodes: &[NodeLine]) -> Result<Self> {
+        let mut network = Network {
+            names: Vec::new(),
+            ids: HashMap::new(),
+            next: Vec::new(),
+        };
+        for (name, _, _) in nodes {
+            ensure!(
+                network.intern(name) == network.next.len(),
+                "Node {} is defined twice",
+                name
+            );
+            network.next.push((0, 0));
+        }
+        for &(name, left, right) in nodes {
+            let next = (network.intern(left), network.intern(right));
+            network.next[network.ids[name]] = next;
+        }
+        ensure!(
+            network.names.len() == network.next.len(),
+            "Node {} is never defined",
+            network.names[network.next.len()]
+        );
+        Ok(network)
+    }
+
+    fn intern(&mut self, name: &str) -> Node {
+        if let Some(&node) = self.ids.get(name) {
+            return node;
+        }
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), self.names.len() - 1);
+        self.names.len() - 1
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn id(&self, name: &str) -> Option<Node> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, node: Node) -> &str {
+        &self.names[node]
+    }
+
+    pub fn step(&self, node: Node, direction: Direction) -> Node {
+        match direction {
+            Direction::Left => self.next[node].0,
+            Direction::Right => self.next[node].1,
+        }
+    }
+
+    /// Whether each node's name matches a pattern, where `?` matches any single character and
+    /// `*` matches any run of characters, so `*Z` matches every name ending in `Z`.
+    pub fn matching(&self, pattern: &str) -> Vec<bool> {
+        let pattern = pattern.chars().collect_vec();
+        self.names
+            .iter()
+            .map(|name| glob_match(&pattern, &name.chars().collect_vec()))
+            .collect()
+    }
+}
+
+fn glob_match(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|i| glob_match(rest, &name[i..])),
+        Some((&p, rest)) => match name.split_first() {
+            Some((&c, name)) => (p == '?' || p == c) && glob_match(rest, name),
+            None => false,
+        },
+    }
+}
+
+fn parse_directions(input: &str) -> IResult<&str, Vec<Direction>> {
+    many1(map(context("L|R", one_of("LR")), |dir| match dir {
+        'L' => Direction::Left,
+        'R' => Direction::Right,
+        _ => unreachable!(),
+    }))(input)
+}
+
+/// A node's name followed by the names of its left and right neighbours.
+type NodeLine<'a> = (&'a str, &'a str, &'a str);
+
+fn parse_node(input: &str) -> IResult<&str, NodeLine<'_>> {
+    tuple((
+        terminated(alphanumeric1, tag(" = (")),
+        terminated(alphanumeric1, tag(", ")),
+        terminated(alphanumeric1, tag(")")),
+    ))(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (Vec<Direction>, Vec<NodeLine<'_>>)> {
+    separated_pair(parse_directions, pair(newline, newline), lines(parse_node))(input)
+}
+
+fn find_end(
+    start: Node,
+    directions: &[Direction],
+    network: &Network,
+    is_end: impl Fn(Node) -> bool,
+) -> usize {
+    let mut node = start;
+    let mut num_steps = 0;
+    let mut dir_iter = directions.iter().cycle();
+    while !is_end(node) {
+        num_steps += 1;
+        node = network.step(node, *dir_iter.next().unwrap());
+    }
+    num_steps
+}
+
+fn part_a(directions: &[Direction], network: &Network) -> Result<usize> {
+    let start = network.id("AAA").context("There is no node AAA")?;
+    let end = network.id("ZZZ").context("There is no node ZZZ")?;
+    Ok(find_end(start, directions, network, |node| node == end))
+}
+
+/// The route a ghost takes from a start node. Its state is the node it's on along with its
+/// position in the directions, which can only take finitely many values, so after some prefix of
+/// steps it must repeat a cycle forever.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    /// The number of steps before the cycle starts.
+    pub prefix: usize,
+    /// The number of steps in the cycle.
+    pub cycle: usize,
+    /// The steps within the prefix at which the ghost is on an end node.
+    pub prefix_ends: Vec<usize>,
+    /// The offsets from the start of the cycle at which the ghost is on an end node.
+    pub cycle_ends: Vec<usize>,
+}
+
+impl Route {
+    pub fn find(
+        start: Node,
+        directions: &[Direction],
+        network: &Network,
+        is_end: impl Fn(Node) -> bool,
+    ) -> Self {
+        // Indexed by node and then position in the directions.
+        let mut seen = vec![None; network.len() * directions.len()];
+        let mut ends = Vec::new();
+        let mut node = start;
+        for step in 0.. {
+            let state = node * directions.len() + step % directions.len();
+            if let Some(prefix) = seen[state] {
+                let (prefix_ends, cycle_ends) = ends.iter().partition(|&&end| end < prefix);
+                return Self {
+                    prefix,
+                    cycle: step - prefix,
+                    prefix_ends,
+                    cycle_ends: cycle_ends.into_iter().map(|end| end - prefix).collect(),
+                };
+            }
+            seen[state] = Some(step);
+
+            if is_end(node) {
+                ends.push(step);
+            }
+            node = network.step(node, directions[step % directions.len()]);
+        }
+        unreachable!()
+    }
+
+    pub fn is_end_at(&self, step: usize) -> bool {
+        if step < self.prefix {
+            self.prefix_ends.binary_search(&step).is_ok()
+        } else {
+            self.cycle_ends
+                .binary_search(&((step - self.prefix) % self.cycle))
+                .is_ok()
+        }
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Solves `x * a = 1 (mod m)` for coprime `a` and `m`.
+fn mod_inverse(a: i128, m: i128) -> i128 {
+    let (mut old_r, mut r) = (a.rem_euclid(m), m);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+    old_s.rem_euclid(m)
+}
+
+/// Combines `x = a1 (mod m1)` and `x = a2 (mod m2)` into a single congruence using the Chinese
+/// Remainder Theorem. The moduli needn't be coprime, in which case there may be no solution.
+/// Fails if the combined modulus doesn't fit in 128 bits, as then no answer can be represented.
+fn crt((a1, m1): (i128, i128), (a2, m2): (i128, i128)) -> Result<Option<(i128, i128)>> {
+    let g = gcd(m1, m2);
+    if (a2 - a1) % g != 0 {
+        return Ok(None);
+    }
+    let overflow = || anyhow!("No answer fits in 128 bits");
+    let lcm = (m1 / g).checked_mul(m2).ok_or_else(overflow)?;
+    let k = ((a2 - a1) / g)
+        .rem_euclid(m2 / g)
+        .checked_mul(mod_inverse(m1 / g, m2 / g))
+        .ok_or_else(overflow)?
+        % (m2 / g);
+    let x = m1
+        .checked_mul(k)
+        .and_then(|x| x.checked_add(a1))
+        .ok_or_else(overflow)?;
+    Ok(Some((x.rem_euclid(lcm), lcm)))
+}
+
+/// The first step at which every route is on an end node at the same time, or `None` if there
+/// isn't one. Fails if there is one but it's too large to count.
+fn first_common_end(routes: &[Route]) -> Result<Option<usize>> {
+    // Before every ghost has reached its cycle the routes don't follow a pattern, so just check
+    // each step.
+    let Some(longest_prefix) = routes.iter().map(|route| route.prefix).max() else {
+        return Ok(None);
+    };
+    if let Some(step) =
+        (0..longest_prefix).find(|&step| routes.iter().all(|route| route.is_end_at(step)))
+    {
+        return Ok(Some(step));
+    }
+
+    // After that each route is on an end node at any step congruent to one of its cycle's end
+    // offsets, so try each combination of offsets.
+    let mut congruences = vec![(0, 1)];
+    for route in routes {
+        let mut combined = Vec::new();
+        for (congruence, end) in congruences.into_iter().cartesian_product(&route.cycle_ends) {
+            let a = (route.prefix + end) as i128;
+            combined.extend(crt(congruence, (a, route.cycle as i128))?);
+        }
+        congruences = combined.into_iter().unique().collect();
+    }
+
+    let start = longest_prefix as i128;
+    let first = congruences
+        .into_iter()
+        .map(|(a, m)| {
+            if a >= start {
+                Some(a)
+            } else {
+                let cycles = (start - a).checked_add(m - 1)? / m;
+                cycles.checked_mul(m)?.checked_add(a)
+            }
+        })
+        .collect::<Option<Vec<_>>>()
+        .context("No answer fits in 128 bits")?
+        .into_iter()
+        .min();
+    first
+        .map(|step| {
+            usize::try_from(step)
+                .with_context(|| format!("The answer {} doesn't fit in a usize", step))
+        })
+        .transpose()
+}
+
+const CYCLE_COLOURS: [&str; 6] = ["blue", "darkorange", "purple", "brown", "deeppink", "cyan4"];
+
+impl Network {
+    /// Renders the network in Graphviz DOT format. Nodes matching `starts` are filled green and
+    /// those matching `ends` red, and the edges of the cycle that the ghost from each start
+    /// settles into are drawn bold, in a different colour for each ghost.
+    pub fn to_dot(&self, directions: &[Direction], starts: &str, ends: &str) -> String {
+        let is_start = self.matching(starts);
+        let is_end = self.matching(ends);
+
+        // The colour of each edge that is part of a cycle, indexed by node and then direction.
+        let mut cycle_edges = vec![[None; 2]; self.len()];
+        for (i, start) in (0..self.len()).filter(|&node| is_start[node]).enumerate() {
+            let route = Route::find(start, directions, self, |node| is_end[node]);
+            let mut node = start;
+            for step in 0..route.prefix + route.cycle {
+                let direction = directions[step % directions.len()];
+                if step >= route.prefix {
+                    cycle_edges[node][direction as usize]
+                        .get_or_insert(CYCLE_COLOURS[i % CYCLE_COLOURS.len()]);
+                }
+                node = self.step(node, direction);
+            }
+        }
+
+        let mut dot = String::from("digraph network {\n");
+        for node in 0..self.len() {
+            let fill = match (is_start[node], is_end[node]) {
+                (true, _) => ", style=filled, fillcolor=palegreen",
+                (false, true) => ", style=filled, fillcolor=lightcoral",
+                (false, false) => "",
+            };
+            writeln!(dot, "    {:?} [shape=circle{}];", self.name(node), fill).unwrap();
+        }
+        for (node, colours) in cycle_edges.iter().enumerate() {
+            for (direction, label) in [(Direction::Left, "L"), (Direction::Right, "R")] {
+                let style = match colours[direction as usize] {
+                    Some(colour) => format!(", color={}, penwidth=3", colour),
+                    None => String::new(),
+                };
+                writeln!(
+                    dot,
+                    "    {:?} -> {:?} [label={}{}];",
+                    self.name(node),
+                    self.name(self.step(node, direction)),
+                    label,
+                    style
+                )
+                .unwrap();
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// The number of steps until the ghosts, one starting on each node matching `starts`, are all on
+/// nodes matching `ends` at the same time. The patterns are as for [`Network::matching`].
+pub fn ghost_steps(
+    directions: &[Direction],
+    network: &Network,
+    starts: &str,
+    ends: &str,
+) -> Result<usize> {
+    let starts = network.matching(starts);
+    let ends = network.matching(ends);
+    let routes = (0..network.len())
+        .filter(|&node| starts[node])
+        .map(|start| Route::find(start, directions, network, |node| ends[node]))
+        .collect_vec();
+
+    first_common_end(&routes)?.context("The ghosts are never all on end nodes at the same time")
+}
+
+fn part_b(directions: &[Direction], network: &Network) -> Result<usize> {
+    ghost_steps(directions, network, "*A", "*Z")
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u32 = 8;
+
+    type Input = (Vec<Direction>, Network);
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (directions, nodes) = parse_all(input, parse_input)?;
+        Ok((directions, Network::new(&nodes)?))
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        part_a(&input.0, &input.1)
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        part_b(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_A1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_A2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+    const EXAMPLE_B: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+    #[test]
+    fn part_a_examples() {
+        let input = Day08::parse(EXAMPLE_A1).unwrap();
+        assert_eq!(Day08::part_a(&input).unwrap(), 2);
+
+        let input = Day08::parse(EXAMPLE_A2).unwrap();
+        assert_eq!(Day08::part_a(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn part_b_example() {
+        let input = Day08::parse(EXAMPLE_B).unwrap();
+        assert_eq!(Day08::part_b(&input).unwrap(), 6);
+    }
+
+    #[test]
+    fn routes_which_break_the_lcm_assumption() {
+        // The first ghost is on an end node every other step. The second reaches one after a
+        // single step and then every third step, so they first meet after 4 steps rather than
+        // the lowest common multiple of 2 and 1.
+        let input = Day08::parse(
+            "\
+L
+
+11A = (11B, XXX)
+11B = (11Z, XXX)
+11Z = (11B, XXX)
+22A = (22Z, XXX)
+22Z = (22B, XXX)
+22B = (22C, XXX)
+22C = (22Z, XXX)
+XXX = (XXX, XXX)
+",
+        )
+        .unwrap();
+        let (directions, network) = &input;
+        let ends = network.matching("*Z");
+        let start = network.id("22A").unwrap();
+        let route = Route::find(start, directions, network, |node| ends[node]);
+        assert_eq!(
+            route,
+            Route {
+                prefix: 1,
+                cycle: 3,
+                prefix_ends: vec![],
+                cycle_ends: vec![0],
+            }
+        );
+        assert_eq!(Day08::part_b(&input).unwrap(), 4);
+    }
+
+    #[test]
+    fn routes_which_never_meet() {
+        let input = Day08::parse(
+            "\
+L
+
+11A = (11Z, XXX)
+11Z = (11A, XXX)
+22A = (22B, XXX)
+22B = (22Z, XXX)
+22Z = (22B, XXX)
+XXX = (XXX, XXX)
+",
+        )
+        .unwrap();
+        assert!(Day08::part_b(&input).is_err());
+    }
+
+    #[test]
+    fn other_patterns() {
+        let input = Day08::parse(
+            "\
+L
+
+START1 = (MID1, XXX)
+MID1 = (GOAL1, XXX)
+GOAL1 = (MID1, XXX)
+START2 = (GOAL2, XXX)
+GOAL2 = (MID2, XXX)
+MID2 = (MID3, XXX)
+MID3 = (GOAL2, XXX)
+XXX = (XXX, XXX)
+",
+        )
+        .unwrap();
+        let (directions, network) = &input;
+        assert_eq!(
+            ghost_steps(directions, network, "START?", "GOAL?").unwrap(),
+            4
+        );
+        assert_eq!(
+            ghost_steps(directions, network, "START1", "MID?").unwrap(),
+            1
+        );
+        assert!(ghost_steps(directions, network, "START*", "MID1").is_err());
+        assert!(Day08::part_b(&input).is_err());
+    }
+
+    #[test]
+    fn crt() {
+        assert_eq!(super::crt((2, 3), (3, 5)).unwrap(), Some((8, 15)));
+        assert_eq!(super::crt((1, 4), (3, 6)).unwrap(), Some((9, 12)));
+        assert_eq!(super::crt((0, 4), (1, 6)).unwrap(), None);
+        assert!(super::crt((0, i128::MAX / 2), (1, 5)).is_err());
+    }
+
+    /// A network where the ghost from each start goes round a loop of nodes with one end node,
+    /// and each loop is a different prime length.
+    fn prime_cycles(primes: &[usize]) -> String {
+        let mut input = String::from("L\n\n");
+        for (ghost, &prime) in primes.iter().enumerate() {
+            let name = |i: usize| {
+                let suffix = match i {
+                    0 => 'A',
+                    i if i == prime - 1 => 'Z',
+                    _ => 'B',
+                };
+                format!("{}N{}{}", ghost, i, suffix)
+            };
+            for i in 0..prime {
+                let next = name((i + 1) % prime);
+                input += &format!("{} = ({}, {})\n", name(i), next, next);
+            }
+        }
+        input
+    }
+
+    #[test]
+    fn answers_too_large_to_count() {
+        // Every ghost is on its end node one step before returning to its start, so they all
+        // meet one step before the product of the primes.
+        let input = Day08::parse(&prime_cycles(&[997, 991, 983])).unwrap();
+        assert_eq!(Day08::part_b(&input).unwrap(), 997 * 991 * 983 - 1);
+
+        let primes = [997, 991, 983, 977, 971, 967, 953];
+        let input = Day08::parse(&prime_cycles(&primes)).unwrap();
+        assert_eq!(
+            Day08::part_b(&input).unwrap_err().to_string(),
+            format!(
+                "The answer {} doesn't fit in a usize",
+                primes.iter().map(|&p| p as i128).product::<i128>() - 1
+            )
+        );
+
+        let primes = [
+            997, 991, 983, 977, 971, 967, 953, 947, 941, 937, 929, 919, 911,
+        ];
+        let input = Day08::parse(&prime_cycles(&primes)).unwrap();
+        assert_eq!(
+            Day08::part_b(&input).unwrap_err().to_string(),
+            "No answer fits in 128 bits"
+        );
+    }
+
+    #[test]
+    fn node_names() {
+        let input = Day08::parse(
+            "\
+LR
+
+start = (a1, bad)
+a1 = (bad, end)
+bad = (bad, bad)
+end = (end, end)
+",
+        )
+        .unwrap();
+        let (directions, network) = &input;
+        assert_eq!(network.len(), 4);
+        assert_eq!(network.name(network.id("a1").unwrap()), "a1");
+        let ends = network.matching("e?d");
+        assert_eq!(ends, [false, false, false, true]);
+        let start = network.id("start").unwrap();
+        assert_eq!(find_end(start, directions, network, |node| ends[node]), 2);
+
+        assert!(Day08::parse("L\n\nAAA = (AAA, BBB)\n").is_err());
+        assert!(Day08::parse("L\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)\n").is_err());
+    }
+
+    #[test]
+    fn glob_match() {
+        let matches = |pattern: &str, name: &str| {
+            super::glob_match(&pattern.chars().collect_vec(), &name.chars().collect_vec())
+        };
+        assert!(matches("*Z", "ZZZ"));
+        assert!(matches("*Z", "Z"));
+        assert!(!matches("*Z", "ZZA"));
+        assert!(matches("A?C", "ABC"));
+        assert!(!matches("A?C", "AC"));
+        assert!(matches("A*C*", "ABBCD"));
+        assert!(matches("*", ""));
+    }
+
+    #[test]
+    fn to_dot() {
+        let (directions, network) = Day08::parse(EXAMPLE_A2).unwrap();
+        assert_eq!(
+            network.to_dot(&directions, "AAA", "ZZZ"),
+            "\
+digraph network {
+    \"AAA\" [shape=circle, style=filled, fillcolor=palegreen];
+    \"BBB\" [shape=circle];
+    \"ZZZ\" [shape=circle, style=filled, fillcolor=lightcoral];
+    \"AAA\" -> \"BBB\" [label=L];
+    \"AAA\" -> \"BBB\" [label=R];
+    \"BBB\" -> \"AAA\" [label=L];
+    \"BBB\" -> \"ZZZ\" [label=R];
+    \"ZZZ\" -> \"ZZZ\" [label=L, color=blue, penwidth=3];
+    \"ZZZ\" -> \"ZZZ\" [label=R, color=blue, penwidth=3];
+}
+"
+        );
+    }
+}
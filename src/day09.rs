@@ -0,0 +1,613 @@
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+use anyhow::{anyhow, Context, Error, Result};
+use itertools::Itertools;
+use nom::{
+    character::complete::{char as achar, digit1, space1},
+    combinator::{map_res, opt, recognize},
+    multi::separated_list1,
+    sequence::tuple,
+};
+use num_integer::Integer;
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, Signed, ToPrimitive,
+};
+
+use crate::{
+    parse::{lines, parse_all, IResult},
+    Solver,
+};
+
+/// The integer types a sequence can be modelled with: `isize`, `i128`, or `BigInt` with the
+/// `bigint` feature. All arithmetic on them is checked, so a value which doesn't fit is reported
+/// rather than wrapping or panicking.
+pub trait Int:
+    Integer
+    + Signed
+    + CheckedAdd
+    + CheckedSub
+    + CheckedMul
+    + CheckedDiv
+    + FromPrimitive
+    + ToPrimitive
+    + FromStr
+    + Clone
+    + Debug
+    + Display
+{
+}
+
+impl<T> Int for T where
+    T: Integer
+        + Signed
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + CheckedDiv
+        + FromPrimitive
+        + ToPrimitive
+        + FromStr
+        + Clone
+        + Debug
+        + Display
+{
+}
+
+fn parse_int<T: Int>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(tuple((opt(achar('-')), digit1))), |s: &str| {
+        s.parse::<T>()
+    })(input)
+}
+
+fn parse_input<T: Int>(input: &str) -> IResult<&str, Vec<Vec<T>>> {
+    lines(separated_list1(space1, parse_int))(input)
+}
+
+fn overflow<T>(what: String) -> Error {
+    anyhow!("{} doesn't fit in {}", what, std::any::type_name::<T>())
+}
+
+/// An exact fraction, always stored in lowest terms with a positive denominator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rational<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T: Int> Rational<T> {
+    pub fn new(numer: T, denom: T) -> Self {
+        assert!(!denom.is_zero(), "Rational with a zero denominator");
+        let g = numer.gcd(&denom) * denom.signum();
+        Self {
+            numer: numer / g.clone(),
+            denom: denom / g,
+        }
+    }
+
+    pub fn numer(&self) -> &T {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &T {
+        &self.denom
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let numer = self
+            .numer
+            .checked_mul(&other.denom)?
+            .checked_add(&other.numer.checked_mul(&self.denom)?)?;
+        Some(Self::new(numer, self.denom.checked_mul(&other.denom)?))
+    }
+}
+
+impl<T: Int> From<T> for Rational<T> {
+    fn from(value: T) -> Self {
+        Self::new(value, T::one())
+    }
+}
+
+impl<T: Int> Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.denom.is_one() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+/// A sequence of readings, modelled as the polynomial which fits them in Newton's forward
+/// difference form: the value at index `n` is the sum over each row `k` of the difference table
+/// of the first difference in that row multiplied by `n choose k`.
+#[derive(Debug, Clone)]
+pub struct Sequence<T = isize> {
+    /// The first value of each row of the difference table, stopping before the row of zeros.
+    leading: Vec<T>,
+    len: usize,
+}
+
+/// Why a sequence of readings couldn't be fitted exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FitError {
+    /// The differences never reach a row of zeros within the given number of readings.
+    NonPolynomial { readings: usize },
+    /// A row of the difference table has a value too large for the integer type.
+    Overflow { row: usize, type_name: &'static str },
+}
+
+impl Display for FitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FitError::NonPolynomial { readings } => write!(
+                f,
+                "The differences never reach a row of zeros within {} readings",
+                readings
+            ),
+            FitError::Overflow { row, type_name } => write!(
+                f,
+                "Row {} of the difference table doesn't fit in {}",
+                row, type_name
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FitError {}
+
+impl<T: Int> Sequence<T> {
+    /// Builds the difference table of the readings. This fails if it runs out of differences
+    /// before reaching a row of zeros, as then nothing shows the readings follow a polynomial.
+    pub fn fit(readings: &[T]) -> Result<Self, FitError> {
+        let mut leading = Vec::new();
+        let mut row = readings.to_vec();
+        while row.iter().any(|n| !n.is_zero()) {
+            if row.len() == 1 {
+                return Err(FitError::NonPolynomial {
+                    readings: readings.len(),
+                });
+            }
+            leading.push(row[0].clone());
+            row = row
+                .iter()
+                .tuple_windows()
+                .map(|(a, b)| b.checked_sub(a))
+                .collect::<Option<_>>()
+                .ok_or_else(|| FitError::Overflow {
+                    row: leading.len(),
+                    type_name: std::any::type_name::<T>(),
+                })?;
+        }
+        Ok(Self {
+            leading,
+            len: readings.len(),
+        })
+    }
+
+    /// The number of readings the sequence was fitted to.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The degree of the polynomial, taking the degree of zero to be zero.
+    pub fn degree(&self) -> usize {
+        self.leading.len().saturating_sub(1)
+    }
+
+    /// The value at any index, where the readings are at `0..len` and negative indices extend
+    /// the sequence backwards.
+    pub fn value_at(&self, index: isize) -> Result<T> {
+        let value_at = || {
+            let index = T::from_isize(index)?;
+            let mut binomial = T::one();
+            let mut value = T::zero();
+            for (k, diff) in self.leading.iter().enumerate() {
+                if k > 0 {
+                    // n choose k from n choose (k - 1). The division is always exact, even for
+                    // negative n, and dividing out the common factor first keeps the
+                    // intermediate product no larger than the result.
+                    let k = T::from_usize(k)?;
+                    let factor = index.checked_sub(&k)?.checked_add(&T::one())?;
+                    let common = binomial.gcd(&k);
+                    binomial = (binomial / common.clone()).checked_mul(&(factor / (k / common)))?;
+                }
+                value = value.checked_add(&diff.checked_mul(&binomial)?)?;
+            }
+            Some(value)
+        };
+        value_at().ok_or_else(|| overflow::<T>(format!("The value at index {}", index)))
+    }
+
+    /// The coefficient of each power of the index, starting with the constant term.
+    pub fn coefficients(&self) -> Result<Vec<Rational<T>>> {
+        let coefficients = || {
+            let mut coefficients = vec![Rational::from(T::zero()); self.degree() + 1];
+            // The coefficients of the falling factorial n(n - 1)...(n - k + 1), and k!.
+            let mut falling = vec![T::one()];
+            let mut factorial = T::one();
+            for (k, diff) in self.leading.iter().enumerate() {
+                if k > 0 {
+                    // Multiply the falling factorial by (n - (k - 1)).
+                    let last = T::from_usize(k - 1)?;
+                    let mut next = vec![T::zero(); falling.len() + 1];
+                    for (i, term) in falling.iter().enumerate() {
+                        next[i + 1] = next[i + 1].checked_add(term)?;
+                        next[i] = next[i].checked_sub(&last.checked_mul(term)?)?;
+                    }
+                    falling = next;
+                    factorial = factorial.checked_mul(&T::from_usize(k)?)?;
+                }
+
+                for (coefficient, term) in coefficients.iter_mut().zip(&falling) {
+                    let term = Rational::new(diff.checked_mul(term)?, factorial.clone());
+                    *coefficient = coefficient.checked_add(&term)?;
+                }
+            }
+            Some(coefficients)
+        };
+        coefficients().ok_or_else(|| overflow::<T>("A coefficient".to_string()))
+    }
+}
+
+/// A least-squares fit of a polynomial to readings which don't follow one exactly.
+#[derive(Debug, Clone)]
+pub struct BestFit {
+    /// The coefficient of each power of the index, starting with the constant term.
+    coefficients: Vec<f64>,
+    /// The root mean square difference between the polynomial and the readings.
+    residual: f64,
+}
+
+impl BestFit {
+    /// Fits a polynomial of the given degree, or of one less than the number of readings if that
+    /// is lower, by solving the normal equations.
+    pub fn fit(readings: &[f64], degree: usize) -> Self {
+        let terms = (degree + 1).min(readings.len());
+        let powers = |x: f64| (0..terms).map(move |i| x.powi(i as i32));
+
+        // The augmented matrix of the normal equations (A^T A | A^T y), where A holds the powers
+        // of each index.
+        let mut matrix = vec![vec![0.0; terms + 1]; terms];
+        for (x, y) in readings.iter().enumerate() {
+            let row = powers(x as f64).collect_vec();
+            for i in 0..terms {
+                for j in 0..terms {
+                    matrix[i][j] += row[i] * row[j];
+                }
+                matrix[i][terms] += row[i] * y;
+            }
+        }
+
+        // Gaussian elimination with partial pivoting, then back substitution.
+        for col in 0..terms {
+            let pivot = (col..terms)
+                .max_by(|&a, &b| matrix[a][col].abs().total_cmp(&matrix[b][col].abs()))
+                .unwrap();
+            matrix.swap(col, pivot);
+            let (upper, lower) = matrix.split_at_mut(col + 1);
+            let pivot = &upper[col];
+            for row in lower {
+                let factor = row[col] / pivot[col];
+                for (value, pivot_value) in row[col..].iter_mut().zip(&pivot[col..]) {
+                    *value -= factor * pivot_value;
+                }
+            }
+        }
+        let mut coefficients = vec![0.0; terms];
+        for row in (0..terms).rev() {
+            let known = (row + 1..terms)
+                .map(|k| matrix[row][k] * coefficients[k])
+                .sum::<f64>();
+            coefficients[row] = (matrix[row][terms] - known) / matrix[row][row];
+        }
+
+        let mut fit = Self {
+            coefficients,
+            residual: 0.0,
+        };
+        let squares = readings
+            .iter()
+            .enumerate()
+            .map(|(x, y)| (fit.value_at(x as f64) - y).powi(2))
+            .sum::<f64>();
+        fit.residual = (squares / readings.len() as f64).sqrt();
+        fit
+    }
+
+    pub fn degree(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    pub fn residual(&self) -> f64 {
+        self.residual
+    }
+
+    pub fn value_at(&self, index: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, coefficient| acc * index + coefficient)
+    }
+}
+
+/// What to do with a line of readings that doesn't follow a polynomial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonPolynomial {
+    /// Fail with an error naming the line.
+    Reject,
+    /// Fall back to a least-squares fit of the given degree.
+    BestFit { degree: usize },
+}
+
+/// How a line of readings is extrapolated.
+#[derive(Debug, Clone)]
+pub enum Model<T = isize> {
+    Exact(Sequence<T>),
+    BestFit { fit: BestFit, len: usize },
+}
+
+impl<T: Int> Model<T> {
+    pub fn len(&self) -> usize {
+        match self {
+            Model::Exact(sequence) => sequence.len(),
+            Model::BestFit { len, .. } => *len,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The value at any index, rounded to the nearest integer for a best fit.
+    pub fn value_at(&self, index: isize) -> Result<T> {
+        match self {
+            Model::Exact(sequence) => sequence.value_at(index),
+            Model::BestFit { fit, .. } => T::from_f64(fit.value_at(index as f64).round())
+                .ok_or_else(|| overflow::<T>(format!("The value at index {}", index))),
+        }
+    }
+}
+
+fn model<T: Int>(readings: &[T], non_polynomial: NonPolynomial) -> Result<Model<T>> {
+    match (Sequence::fit(readings), non_polynomial) {
+        (Ok(sequence), _) => Ok(Model::Exact(sequence)),
+        // Readings too large to fit exactly are still reported, rather than approximated.
+        (Err(FitError::NonPolynomial { .. }), NonPolynomial::BestFit { degree }) => {
+            let readings = readings
+                .iter()
+                .map(|reading| reading.to_f64().context("Reading is too large"))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Model::BestFit {
+                fit: BestFit::fit(&readings, degree),
+                len: readings.len(),
+            })
+        }
+        (Err(error), _) => Err(error.into()),
+    }
+}
+
+/// Models each line of readings, naming the line if it can't be.
+pub fn parse_models<T: Int>(input: &str, non_polynomial: NonPolynomial) -> Result<Vec<Model<T>>> {
+    parse_all(input, parse_input::<T>)?
+        .iter()
+        .enumerate()
+        .map(|(i, readings)| {
+            model(readings, non_polynomial).with_context(|| format!("Sequence on line {}", i + 1))
+        })
+        .collect()
+}
+
+fn sum_values_at<T: Int>(models: &[Model<T>], index: impl Fn(&Model<T>) -> isize) -> Result<T> {
+    models
+        .iter()
+        .enumerate()
+        .try_fold(T::zero(), |acc, (i, model)| {
+            let value = model
+                .value_at(index(model))
+                .with_context(|| format!("Sequence on line {}", i + 1))?;
+            acc.checked_add(&value)
+                .ok_or_else(|| overflow::<T>("The sum of the values".to_string()))
+        })
+}
+
+fn part_a<T: Int>(models: &[Model<T>]) -> Result<T> {
+    sum_values_at(models, |model| model.len() as isize)
+}
+
+fn part_b<T: Int>(models: &[Model<T>]) -> Result<T> {
+    sum_values_at(models, |_| -1)
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u32 = 9;
+
+    type Input = Vec<Model>;
+    type Output = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_models(input, NonPolynomial::Reject)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        part_a(input)
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        part_b(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part_a(&input).unwrap(), 114);
+    }
+
+    #[test]
+    fn part_b_example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part_b(&input).unwrap(), 2);
+    }
+
+    #[test]
+    fn negative_readings() {
+        let input = Day09::parse("-1 -3 -5\n").unwrap();
+        assert_eq!(Day09::part_a(&input).unwrap(), -7);
+        assert_eq!(Day09::part_b(&input).unwrap(), 1);
+    }
+
+    fn fit(readings: &[isize]) -> Sequence {
+        Sequence::fit(readings).unwrap()
+    }
+
+    #[test]
+    fn value_at() {
+        let sequence = fit(&[1, 3, 6, 10, 15, 21]);
+        assert_eq!(sequence.degree(), 2);
+        assert_eq!(sequence.value_at(0).unwrap(), 1);
+        assert_eq!(sequence.value_at(6).unwrap(), 28);
+        assert_eq!(sequence.value_at(999).unwrap(), 500500);
+        assert_eq!(sequence.value_at(-1).unwrap(), 0);
+        assert_eq!(sequence.value_at(-5).unwrap(), 6);
+
+        let sequence = fit(&[7, 7, 7]);
+        assert_eq!(sequence.degree(), 0);
+        assert_eq!(sequence.value_at(-100).unwrap(), 7);
+        assert_eq!(fit(&[0, 0]).value_at(5).unwrap(), 0);
+    }
+
+    #[test]
+    fn coefficients() {
+        let coefficients = fit(&[1, 3, 6, 10, 15, 21]).coefficients().unwrap();
+        assert_eq!(
+            coefficients,
+            [
+                Rational::new(1, 1),
+                Rational::new(3, 2),
+                Rational::new(1, 2)
+            ]
+        );
+        assert_eq!(coefficients[1].to_string(), "3/2");
+
+        // n^3 - 2n + 5
+        let readings = (0..6).map(|n| n * n * n - 2 * n + 5).collect_vec();
+        assert_eq!(
+            fit(&readings).coefficients().unwrap(),
+            [5, -2, 0, 1].map(Rational::from)
+        );
+        assert_eq!(Rational::new(4, -6), Rational::new(-2, 3));
+    }
+
+    #[test]
+    fn overflow() {
+        let max = isize::MAX;
+        let input = format!("1 2 3\n{} {} {} {}\n", -max, max, max, -max);
+        let error = parse_models::<isize>(&input, NonPolynomial::Reject).unwrap_err();
+        assert_eq!(
+            format!("{:#}", error),
+            "Sequence on line 2: Row 1 of the difference table doesn't fit in isize"
+        );
+
+        // Overflow isn't mistaken for readings which don't follow a polynomial.
+        let best_fit = NonPolynomial::BestFit { degree: 1 };
+        let error = parse_models::<isize>(&input, best_fit).unwrap_err();
+        assert_eq!(
+            error.root_cause().downcast_ref(),
+            Some(&FitError::Overflow {
+                row: 1,
+                type_name: "isize"
+            })
+        );
+
+        let sequences = parse_models::<i128>(&input, NonPolynomial::Reject).unwrap();
+        assert_eq!(sequences[1].value_at(1).unwrap(), isize::MAX as i128);
+
+        // n^6
+        let readings = (0..8).map(|n: isize| n.pow(6)).collect_vec();
+        let error = fit(&readings).value_at(100_000).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The value at index 100000 doesn't fit in isize"
+        );
+        let readings = readings.into_iter().map(|n| n as i128).collect_vec();
+        let sequence = Sequence::fit(&readings).unwrap();
+        assert_eq!(sequence.value_at(100_000).unwrap(), 10i128.pow(30));
+
+        // n choose 3, whose value fits even though n choose 2 times n doesn't.
+        let sequence = fit(&[0, 0, 0, 1, 4, 10, 20]);
+        assert_eq!(
+            sequence.value_at(3_000_000).unwrap(),
+            4_499_995_500_001_000_000
+        );
+        assert_eq!(sequence.coefficients().unwrap().len(), 4);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn bigint() {
+        use num_bigint::BigInt;
+
+        let readings = (0..8).map(|n: u32| BigInt::from(n).pow(6)).collect_vec();
+        let sequence = Sequence::fit(&readings).unwrap();
+        let value = sequence.value_at(100_000_000).unwrap();
+        assert_eq!(value, BigInt::from(10).pow(48));
+
+        let input = format!("{0} {0} {0}\n", "9".repeat(50));
+        assert!(parse_models::<i128>(&input, NonPolynomial::Reject).is_err());
+        assert!(parse_models::<BigInt>(&input, NonPolynomial::Reject).is_ok());
+    }
+
+    #[test]
+    fn non_polynomial() {
+        let input = "1 2 3\n1 2 4 8 16\n";
+        let error = Day09::parse(input).unwrap_err();
+        assert_eq!(
+            format!("{:#}", error),
+            "Sequence on line 2: The differences never reach a row of zeros within 5 readings"
+        );
+        assert!(Day09::parse("5\n").is_err());
+
+        let models = parse_models::<isize>(input, NonPolynomial::BestFit { degree: 2 }).unwrap();
+        assert!(matches!(models[0], Model::Exact(_)));
+        let Model::BestFit { fit, .. } = &models[1] else {
+            panic!("Expected a best fit");
+        };
+        assert_eq!(fit.degree(), 2);
+        assert!(fit.residual() > 0.0 && fit.residual() < 1.0);
+        // 9/7 - 34n/35 + 8n^2/7
+        assert_eq!(models[1].value_at(5).unwrap(), 25);
+    }
+
+    #[test]
+    fn best_fit_of_an_exact_polynomial() {
+        let fit = BestFit::fit(&[1.0, 3.0, 6.0, 10.0, 15.0, 21.0], 3);
+        for (coefficient, expected) in fit.coefficients().iter().zip([1.0, 1.5, 0.5, 0.0]) {
+            assert!((coefficient - expected).abs() < 1e-9);
+        }
+        assert!(fit.residual() < 1e-9);
+        assert_eq!(BestFit::fit(&[4.0, 6.0], 5).degree(), 1);
+    }
+}
@@ -0,0 +1,649 @@
+use std::fmt::Display;
+
+use anyhow::{bail, Context, Result};
+use bitflags::bitflags;
+
+use crate::{
+    grid::{Grid, Pos},
+    polygon, Solver,
+};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub struct Pipe: u32 {
+        const NORTH = 0b00000001;
+        const SOUTH = 0b00000010;
+        const EAST  = 0b00000100;
+        const WEST  = 0b00001000;
+    }
+}
+
+impl Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match *self {
+            p if p == Pipe::NORTH | Pipe::SOUTH => '|',
+            p if p == Pipe::EAST | Pipe::WEST => '-',
+            p if p == Pipe::NORTH | Pipe::EAST => 'L',
+            p if p == Pipe::NORTH | Pipe::WEST => 'J',
+            p if p == Pipe::SOUTH | Pipe::WEST => '7',
+            p if p == Pipe::SOUTH | Pipe::EAST => 'F',
+            p if p.is_empty() => '.',
+            _ => '?',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+pub struct Map {
+    pipes: Grid<Pipe>,
+}
+
+impl Map {
+    fn pipe(&self, pos: Pos) -> Pipe {
+        self.pipes[pos]
+    }
+
+    fn is_connected(&self, a: Pos, b: Pos) -> bool {
+        match (b.x - a.x, b.y - a.y) {
+            (0, 1) => self.pipe(a).contains(Pipe::SOUTH) && self.pipe(b).contains(Pipe::NORTH),
+            (0, -1) => self.pipe(a).contains(Pipe::NORTH) && self.pipe(b).contains(Pipe::SOUTH),
+            (1, 0) => self.pipe(a).contains(Pipe::EAST) && self.pipe(b).contains(Pipe::WEST),
+            (-1, 0) => self.pipe(a).contains(Pipe::WEST) && self.pipe(b).contains(Pipe::EAST),
+            _ => false,
+        }
+    }
+
+    // Iterator yielding the direction and position of all neighbours that are connected.
+    fn connected_neighbours(&self, pos: Pos) -> impl Iterator<Item = (Pipe, Pos)> + '_ {
+        [
+            (Pipe::WEST, Pos::new(pos.x - 1, pos.y)),
+            (Pipe::EAST, Pos::new(pos.x + 1, pos.y)),
+            (Pipe::NORTH, Pos::new(pos.x, pos.y - 1)),
+            (Pipe::SOUTH, Pos::new(pos.x, pos.y + 1)),
+        ]
+        .into_iter()
+        .filter(|(_direction, pos)| self.pipes.contains(*pos))
+        .filter(move |(_dir, neighbour_pos)| self.is_connected(pos, *neighbour_pos))
+    }
+
+    /// Marks which tiles are on the loop and which are enclosed by it. Scanning along each row,
+    /// a tile is enclosed if it's to the east of an odd number of loop tiles with a pipe to the
+    /// north.
+    fn classify(&self, pipe_loop: &[Pos]) -> (Grid<bool>, Grid<bool>) {
+        let (width, height) = (self.pipes.width(), self.pipes.height());
+        let mut on_loop = Grid::new(width, height, false);
+        for pos in pipe_loop {
+            on_loop[*pos] = true;
+        }
+
+        let mut enclosed = Grid::new(width, height, false);
+        for y in 0..height as isize {
+            let mut inside = false;
+            for x in 0..width as isize {
+                let pos = Pos::new(x, y);
+                if on_loop[pos] {
+                    inside ^= self.pipe(pos).contains(Pipe::NORTH);
+                } else {
+                    enclosed[pos] = inside;
+                }
+            }
+        }
+        (on_loop, enclosed)
+    }
+
+    /// Finds the pipes connected to the one at `anchor`. If they form a closed loop its tiles are
+    /// given in order around it, starting from the anchor.
+    pub fn component_at(&self, anchor: Pos) -> Result<Component> {
+        match self.pipes.get(anchor) {
+            None => bail!("{:?} is outside the map", anchor),
+            Some(pipe) if pipe.is_empty() => bail!("There's no pipe at {:?}", anchor),
+            Some(_) => {}
+        }
+
+        let mut seen = Grid::new(self.pipes.width(), self.pipes.height(), false);
+        Ok(self.component(anchor, &mut seen))
+    }
+
+    /// Splits every pipe on the map into connected components, in order of their first tile
+    /// reading row by row. Each component is anchored at that tile.
+    pub fn components(&self) -> Vec<Component> {
+        let mut seen = Grid::new(self.pipes.width(), self.pipes.height(), false);
+        let mut components = Vec::new();
+        for (pos, pipe) in self.pipes.iter() {
+            if !pipe.is_empty() && !seen[pos] {
+                components.push(self.component(pos, &mut seen));
+            }
+        }
+        components
+    }
+
+    /// Searches out from the anchor through the connected pipes, marking each one found as seen.
+    /// The anchor must be a pipe which hasn't been seen yet.
+    fn component(&self, anchor: Pos, seen: &mut Grid<bool>) -> Component {
+        seen[anchor] = true;
+        let mut tiles = vec![anchor];
+        let mut closed = true;
+        let mut i = 0;
+        while let Some(&pos) = tiles.get(i) {
+            let mut degree = 0;
+            for (_, neighbour) in self.connected_neighbours(pos) {
+                degree += 1;
+                if !seen[neighbour] {
+                    seen[neighbour] = true;
+                    tiles.push(neighbour);
+                }
+            }
+            // Every tile of a loop connects to exactly two others, and any connected set of
+            // tiles which all do so must be a single loop.
+            closed &= degree == 2;
+            i += 1;
+        }
+
+        if closed {
+            tiles = find_loop(self, anchor).expect("A closed component is a single loop");
+        }
+        Component { tiles, closed }
+    }
+
+    /// Counts the tiles enclosed by the loop by scanning the map, as an alternative to the polygon
+    /// area used for part B.
+    pub fn count_enclosed(&self, pipe_loop: &[Pos]) -> usize {
+        let (_, enclosed) = self.classify(pipe_loop);
+        enclosed.iter().filter(|(_, inside)| **inside).count()
+    }
+
+    /// Redraws the map with box-drawing characters. The loop is drawn in heavy lines and other
+    /// pipes in light ones. Without colour, enclosed tiles are marked with `●` and ground outside
+    /// the loop with `·`. With ANSI colour, pipes off the loop are dimmed rather than hidden and
+    /// enclosed tiles are given a green background instead.
+    pub fn render(&self, pipe_loop: &[Pos], colour: bool) -> String {
+        const DIM: &str = "\x1b[2m";
+        const BOLD: &str = "\x1b[1m";
+        const ENCLOSED: &str = "\x1b[42m";
+        const RESET: &str = "\x1b[0m";
+
+        let (on_loop, enclosed) = self.classify(pipe_loop);
+        let mut output = String::new();
+        for (pos, pipe) in self.pipes.iter() {
+            if pos.x == 0 && pos.y > 0 {
+                output.push('\n');
+            }
+            let c = if on_loop[pos] {
+                box_drawing(*pipe, true)
+            } else if enclosed[pos] && !colour {
+                '●'
+            } else if pipe.is_empty() {
+                '·'
+            } else {
+                box_drawing(*pipe, false)
+            };
+
+            if !colour {
+                output.push(c);
+            } else if on_loop[pos] {
+                output.extend([BOLD, c.encode_utf8(&mut [0; 4]), RESET]);
+            } else {
+                let background = if enclosed[pos] { ENCLOSED } else { "" };
+                output.extend([DIM, background, c.encode_utf8(&mut [0; 4]), RESET]);
+            }
+        }
+        output.push('\n');
+        output
+    }
+}
+
+/// A set of pipes connected to each other, which either form a closed loop or dangle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Component {
+    tiles: Vec<Pos>,
+    closed: bool,
+}
+
+impl Component {
+    /// The tiles of the component, starting from its anchor. A loop's tiles are in order around
+    /// it.
+    pub fn tiles(&self) -> &[Pos] {
+        &self.tiles
+    }
+
+    pub fn anchor(&self) -> Pos {
+        self.tiles[0]
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn is_loop(&self) -> bool {
+        self.closed
+    }
+
+    /// The number of tiles enclosed by a loop, or `None` if the pipes dangle.
+    pub fn enclosed(&self) -> Option<usize> {
+        if self.closed {
+            polygon::interior_points(&self.tiles)
+        } else {
+            None
+        }
+    }
+}
+
+fn box_drawing(pipe: Pipe, heavy: bool) -> char {
+    let (light, heavy_char) = match pipe {
+        p if p == Pipe::NORTH | Pipe::SOUTH => ('│', '┃'),
+        p if p == Pipe::EAST | Pipe::WEST => ('─', '━'),
+        p if p == Pipe::NORTH | Pipe::EAST => ('└', '┗'),
+        p if p == Pipe::NORTH | Pipe::WEST => ('┘', '┛'),
+        p if p == Pipe::SOUTH | Pipe::WEST => ('┐', '┓'),
+        p if p == Pipe::SOUTH | Pipe::EAST => ('┌', '┏'),
+        _ => ('?', '?'),
+    };
+    if heavy {
+        heavy_char
+    } else {
+        light
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Map, Option<Pos>)> {
+    let mut start_pos = None;
+    let pipes = Grid::parse(input, |pos, c| {
+        Ok(match c {
+            '|' => Pipe::NORTH | Pipe::SOUTH,
+            '-' => Pipe::EAST | Pipe::WEST,
+            'L' => Pipe::NORTH | Pipe::EAST,
+            'J' => Pipe::NORTH | Pipe::WEST,
+            '7' => Pipe::SOUTH | Pipe::WEST,
+            'F' => Pipe::SOUTH | Pipe::EAST,
+            '.' => Pipe::empty(),
+            'S' => {
+                if start_pos.is_some() {
+                    bail!("Duplicate start positions found");
+                }
+                start_pos = Some(pos);
+                // The shape of the start tile is worked out later in fix_start().
+                Pipe::empty()
+            }
+            _ => bail!("Unexpected character {:?} found", c),
+        })
+    })?;
+
+    Ok((Map { pipes }, start_pos))
+}
+
+/// Parses a map for analysis, where the start tile is optional. If there is one, it's given the
+/// only shape which closes a loop through it. Otherwise it's left as ground, and the reason is
+/// returned alongside the map.
+pub fn parse_map(input: &str) -> Result<(Map, Option<StartError>)> {
+    let (mut map, start_pos) = parse_input(input)?;
+    let start_error = start_pos.and_then(|start_pos| fix_start(&mut map, start_pos).err());
+    Ok((map, start_error))
+}
+
+/// The shapes a pipe can have, each connecting two directions.
+const SHAPES: [Pipe; 6] = [
+    Pipe::NORTH.union(Pipe::SOUTH),
+    Pipe::EAST.union(Pipe::WEST),
+    Pipe::NORTH.union(Pipe::EAST),
+    Pipe::NORTH.union(Pipe::WEST),
+    Pipe::SOUTH.union(Pipe::WEST),
+    Pipe::SOUTH.union(Pipe::EAST),
+];
+
+/// Why the shape of the start tile couldn't be worked out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StartError {
+    /// No shape for the start tile closes a loop through it.
+    NoLoop,
+    /// More than one shape closes a loop, each of which is given.
+    Ambiguous(Vec<Pipe>),
+}
+
+impl Display for StartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartError::NoLoop => write!(f, "No shape for the start tile closes a loop"),
+            StartError::Ambiguous(shapes) => {
+                write!(f, "The start tile closes a loop as any of ")?;
+                for (i, shape) in shapes.iter().enumerate() {
+                    write!(f, "{}{}", if i > 0 { ", " } else { "" }, shape)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for StartError {}
+
+/// Tries each shape for the start tile, keeping the only one which closes a loop through it, and
+/// returns that loop.
+fn fix_start(map: &mut Map, start_pos: Pos) -> Result<Vec<Pos>, StartError> {
+    let mut loops = Vec::new();
+    for shape in SHAPES {
+        map.pipes[start_pos] = shape;
+        if map.connected_neighbours(start_pos).count() == 2 {
+            if let Ok(pipe_loop) = find_loop(map, start_pos) {
+                loops.push((shape, pipe_loop));
+            }
+        }
+    }
+
+    map.pipes[start_pos] = Pipe::empty();
+    match loops.len() {
+        0 => Err(StartError::NoLoop),
+        1 => {
+            let (shape, pipe_loop) = loops.pop().unwrap();
+            map.pipes[start_pos] = shape;
+            Ok(pipe_loop)
+        }
+        _ => Err(StartError::Ambiguous(
+            loops.into_iter().map(|(shape, _)| shape).collect(),
+        )),
+    }
+}
+
+/// Walks around the loop from the start, returning each position on it in order.
+fn find_loop(map: &Map, start_pos: Pos) -> Result<Vec<Pos>> {
+    let mut path = vec![start_pos];
+    let mut prev = None;
+    let mut pos = start_pos;
+    loop {
+        let (_, next) = map
+            .connected_neighbours(pos)
+            .find(|(_, neighbour)| Some(*neighbour) != prev)
+            .with_context(|| format!("The pipe loop is broken at {:?}", pos))?;
+        if next == start_pos {
+            return Ok(path);
+        }
+        path.push(next);
+        (prev, pos) = (Some(pos), next);
+    }
+}
+
+fn part_a(pipe_loop: &[Pos]) -> usize {
+    pipe_loop.len() / 2
+}
+
+fn part_b(pipe_loop: &[Pos]) -> Result<usize> {
+    // Each tile on the loop is a boundary point of the polygon it traces through the tile
+    // centres, and each enclosed tile an interior point.
+    polygon::interior_points(pipe_loop).context("The pipe loop doesn't enclose an area")
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = (Map, Vec<Pos>);
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (mut map, start_pos) = parse_input(input)?;
+        let start_pos = start_pos.context("No start position found")?;
+
+        let pipe_loop = fix_start(&mut map, start_pos)?;
+        Ok((map, pipe_loop))
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        Ok(part_a(&input.1))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        part_b(&input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE_A1: &str = "\
+.....
+.S-7.
+.|.|.
+.L-J.
+.....
+";
+
+    const EXAMPLE_A2: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+    const EXAMPLE_B1: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+    // The inner area is only reachable from outside by squeezing between pipes.
+    const EXAMPLE_B2: &str = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........
+";
+
+    const EXAMPLE_B3: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+    const EXAMPLE_B4: &str = "\
+FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L
+";
+
+    #[test]
+    fn part_a_examples() {
+        let input = Day10::parse(EXAMPLE_A1).unwrap();
+        assert_eq!(Day10::part_a(&input).unwrap(), 4);
+
+        let input = Day10::parse(EXAMPLE_A2).unwrap();
+        assert_eq!(Day10::part_a(&input).unwrap(), 8);
+    }
+
+    #[test]
+    fn part_b_examples() {
+        for (example, expected) in [
+            (EXAMPLE_B1, 4),
+            (EXAMPLE_B2, 4),
+            (EXAMPLE_B3, 8),
+            (EXAMPLE_B4, 10),
+        ] {
+            let input = Day10::parse(example).unwrap();
+            assert_eq!(Day10::part_b(&input).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn loop_path() {
+        let (mut map, start_pos) = parse_input(EXAMPLE_A1).unwrap();
+        let start_pos = start_pos.unwrap();
+        let path = fix_start(&mut map, start_pos).unwrap();
+        assert_eq!(path.len(), 8);
+        assert_eq!(path[0], start_pos);
+        for (a, b) in path.iter().zip(path.iter().cycle().skip(1)) {
+            assert!(map.is_connected(*a, *b));
+        }
+    }
+
+    #[test]
+    fn scan_matches_polygon_area() {
+        for example in [
+            EXAMPLE_A1, EXAMPLE_A2, EXAMPLE_B1, EXAMPLE_B2, EXAMPLE_B3, EXAMPLE_B4,
+        ] {
+            let (map, pipe_loop) = Day10::parse(example).unwrap();
+            assert_eq!(
+                polygon::interior_points(&pipe_loop),
+                Some(map.count_enclosed(&pipe_loop))
+            );
+        }
+    }
+
+    #[test]
+    fn render() {
+        let (map, pipe_loop) = Day10::parse(
+            "\
+-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF
+",
+        )
+        .unwrap();
+        assert_eq!(
+            map.render(&pipe_loop, false),
+            "\
+─└│┌┐
+┐┏━┓│
+└┃●┃│
+─┗━┛│
+└│─┘┌
+"
+        );
+
+        let rendered = map.render(&pipe_loop, true);
+        assert!(rendered.contains("\x1b[1m┏\x1b[0m"));
+        assert!(rendered.contains("\x1b[2m\x1b[42m┐\x1b[0m"));
+        assert!(rendered.contains("\x1b[2m└\x1b[0m"));
+    }
+
+    #[test]
+    fn start_shape() {
+        // Four pipes point at the start, but only one pair of them forms a loop.
+        let (map, _) = Day10::parse(
+            "\
+.|...
+-S-7.
+.|.|.
+.L-J.
+",
+        )
+        .unwrap();
+        assert_eq!(map.pipe(Pos::new(1, 1)), Pipe::SOUTH | Pipe::EAST);
+
+        let Err(error) = Day10::parse("S-.\n") else {
+            panic!("A lone pipe shouldn't form a loop");
+        };
+        assert_eq!(error.downcast_ref(), Some(&StartError::NoLoop));
+
+        // The start is where the two loops of a figure of eight cross.
+        let Err(error) = Day10::parse(
+            "\
+F-7..
+|.|..
+L-S-7
+..|.|
+..L-J
+",
+        ) else {
+            panic!("A figure of eight shouldn't have a single loop");
+        };
+        assert_eq!(
+            error.downcast_ref(),
+            Some(&StartError::Ambiguous(vec![
+                Pipe::NORTH | Pipe::WEST,
+                Pipe::SOUTH | Pipe::EAST
+            ]))
+        );
+        assert_eq!(
+            error.to_string(),
+            "The start tile closes a loop as any of J, F"
+        );
+    }
+
+    #[test]
+    fn components() {
+        let (map, start_error) = parse_map(
+            "\
+F7.F-7
+LJ.|.|
+-7.L-J
+.L-.F.
+",
+        )
+        .unwrap();
+        assert_eq!(start_error, None);
+        let components = map.components();
+        assert_eq!(
+            components
+                .iter()
+                .map(|c| (c.anchor(), c.len(), c.enclosed()))
+                .collect::<Vec<_>>(),
+            vec![
+                (Pos::new(0, 0), 4, Some(0)),
+                (Pos::new(3, 0), 8, Some(1)),
+                (Pos::new(0, 2), 4, None),
+                (Pos::new(4, 3), 1, None),
+            ]
+        );
+
+        // Anchoring anywhere on a loop walks the same loop from there.
+        let component = map.component_at(Pos::new(5, 1)).unwrap();
+        assert!(component.is_loop());
+        assert_eq!(component.anchor(), Pos::new(5, 1));
+        let mut tiles = component.tiles().to_vec();
+        tiles.sort();
+        let mut expected = components[1].tiles().to_vec();
+        expected.sort();
+        assert_eq!(tiles, expected);
+
+        assert!(map.component_at(Pos::new(2, 0)).is_err());
+        assert!(map.component_at(Pos::new(6, 0)).is_err());
+
+        // The start tile is given its shape before the analysis.
+        let (map, start_error) = parse_map(EXAMPLE_B1).unwrap();
+        assert_eq!(start_error, None);
+        let components = map.components();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].enclosed(), Some(4));
+
+        // A start tile which doesn't close a loop is left as ground.
+        let (map, start_error) = parse_map("S-.\n...\n").unwrap();
+        assert_eq!(start_error, Some(StartError::NoLoop));
+        assert_eq!(map.pipe(Pos::new(0, 0)), Pipe::empty());
+        let components = map.components();
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].anchor(), Pos::new(1, 0));
+        assert!(!components[0].is_loop());
+    }
+}
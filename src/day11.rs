@@ -0,0 +1,132 @@
+use anyhow::{bail, Result};
+use itertools::Itertools;
+
+use crate::{
+    grid::{Grid, Pos},
+    Solver,
+};
+
+/// Moves each coordinate along one axis by `amount` for every empty line before it. The lines
+/// before a coordinate which hold a galaxy are counted by binary search.
+fn expand_axis(coords: &mut [isize], amount: isize) {
+    let mut occupied = coords.to_vec();
+    occupied.sort_unstable();
+    occupied.dedup();
+
+    for coord in coords {
+        let empty = *coord - occupied.partition_point(|line| line < coord) as isize;
+        *coord += empty * amount;
+    }
+}
+
+fn expand(galaxies: &[Pos], amount: isize) -> Vec<Pos> {
+    let mut xs = galaxies.iter().map(|galaxy| galaxy.x).collect_vec();
+    let mut ys = galaxies.iter().map(|galaxy| galaxy.y).collect_vec();
+    expand_axis(&mut xs, amount);
+    expand_axis(&mut ys, amount);
+
+    xs.into_iter().zip(ys).map(|(x, y)| Pos { x, y }).collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pos>> {
+    let image = Grid::parse(input, |_pos, c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => bail!("Unexpected character {:?} found", c),
+    })?;
+
+    Ok(image
+        .iter()
+        .filter_map(|(pos, galaxy)| galaxy.then_some(pos))
+        .collect())
+}
+
+/// Sums the distances between every pair of coordinates along one axis. Once sorted, each
+/// coordinate is the larger of the pair with every one before it, so its distance to all of them
+/// comes from a running prefix sum.
+fn axis_distances(mut coords: Vec<isize>) -> usize {
+    coords.sort_unstable();
+    let mut prefix = 0;
+    let mut total = 0;
+    for (i, coord) in coords.into_iter().enumerate() {
+        total += coord as usize * i - prefix;
+        prefix += coord as usize;
+    }
+    total
+}
+
+/// Sums the Manhattan distances between every pair of galaxies, one axis at a time.
+fn distances(galaxies: &[Pos]) -> usize {
+    axis_distances(galaxies.iter().map(|galaxy| galaxy.x).collect())
+        + axis_distances(galaxies.iter().map(|galaxy| galaxy.y).collect())
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Vec<Pos>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        Ok(distances(&expand(input, 1)))
+    }
+
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        Ok(distances(&expand(input, 999999)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part_a(&input).unwrap(), 374);
+    }
+
+    #[test]
+    fn larger_expansions() {
+        let galaxies = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(distances(&expand(&galaxies, 9)), 1030);
+        assert_eq!(distances(&expand(&galaxies, 99)), 8410);
+    }
+
+    #[test]
+    fn matches_pairwise_sum() {
+        let galaxies = expand(&Day11::parse(EXAMPLE).unwrap(), 9);
+        let pairwise: usize = galaxies
+            .iter()
+            .tuple_combinations()
+            .map(|(a, b)| ((b.x - a.x).abs() + (b.y - a.y).abs()) as usize)
+            .sum();
+        assert_eq!(distances(&galaxies), pairwise);
+
+        // Galaxies sharing a row or column, with empty lines before the first of them.
+        let galaxies = vec![Pos::new(2, 1), Pos::new(2, 4), Pos::new(5, 4)];
+        assert_eq!(
+            expand(&galaxies, 9),
+            vec![Pos::new(20, 10), Pos::new(20, 31), Pos::new(41, 31)]
+        );
+    }
+}
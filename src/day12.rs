@@ -1,12 +1,17 @@
 use std::collections::HashMap;
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use nom::{
-    character::complete::{char as achar, digit1, newline, one_of, space1},
-    combinator::{map, map_res},
+    character::complete::{char as achar, digit1, one_of, space1},
+    combinator::{cut, map, map_res},
+    error::context,
     multi::{many1, separated_list1},
     sequence::separated_pair,
-    IResult,
+};
+
+use crate::{
+    parse::{lines, parse_all, IResult},
+    Solver,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -26,7 +31,8 @@ impl Spring {
     }
 }
 
-struct Row {
+#[derive(Clone)]
+pub struct Row {
     springs: Vec<Spring>,
     damaged: Vec<usize>,
 }
@@ -36,7 +42,7 @@ fn parse_usize(input: &str) -> IResult<&str, usize> {
 }
 
 fn parse_spring(input: &str) -> IResult<&str, Spring> {
-    map(one_of(".#?"), |c| match c {
+    map(context(".|#|?", one_of(".#?")), |c| match c {
         '.' => Spring::Operational,
         '#' => Spring::Damaged,
         '?' => Spring::Unknown,
@@ -45,17 +51,14 @@ fn parse_spring(input: &str) -> IResult<&str, Spring> {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Row>> {
-    separated_list1(
-        newline,
-        map(
-            separated_pair(
-                many1(parse_spring),
-                space1,
-                separated_list1(achar(','), parse_usize),
-            ),
-            |(springs, damaged)| Row { springs, damaged },
+    lines(map(
+        separated_pair(
+            many1(parse_spring),
+            space1,
+            separated_list1(achar(','), cut(parse_usize)),
         ),
-    )(input)
+        |(springs, damaged)| Row { springs, damaged },
+    ))(input)
 }
 
 fn num_arrangements<'a>(
@@ -132,18 +135,51 @@ fn unfold(rows: &mut [Row], n: usize) {
     }
 }
 
-fn main() -> Result<()> {
-    let input = std::fs::read_to_string("res/input12.txt")?;
-    let mut rows = parse_input(&input)
-        .map_err(|e| anyhow!("Error parsing input: {:?}", e))?
-        .1;
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Vec<Row>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(parse_all(input, parse_input)?)
+    }
+
+    fn part_a(input: &Self::Input) -> Result<Self::Output> {
+        Ok(solve(input))
+    }
 
-    let part_a = solve(&rows);
-    println!("Day 12, part A: {}", part_a);
+    fn part_b(input: &Self::Input) -> Result<Self::Output> {
+        let mut rows = input.clone();
+        unfold(&mut rows, 5);
+        Ok(solve(&rows))
+    }
+}
 
-    unfold(&mut rows, 5);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+    #[test]
+    fn part_a_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part_a(&input).unwrap(), 21);
+    }
 
-    let part_b = solve(&rows);
-    println!("Day 12, part B: {}", part_b);
-    Ok(())
+    #[test]
+    fn part_b_example() {
+        let input = Day12::parse(EXAMPLE).unwrap();
+        assert_eq!(Day12::part_b(&input).unwrap(), 525152);
+    }
 }
@@ -0,0 +1,252 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use anyhow::{bail, Context, Result};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Pos {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Pos {
+    pub fn new(x: isize, y: isize) -> Self {
+        Pos { x, y }
+    }
+
+    pub fn offset(self, dx: isize, dy: isize) -> Self {
+        Pos::new(self.x + dx, self.y + dy)
+    }
+}
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular 2D grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`. The width must be non-zero, as rows are
+    /// sliced out of the cells by it.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        assert!(width > 0, "Grid width must be non-zero");
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, converting each character along with its position into a cell.
+    /// Every line must be the same width.
+    pub fn parse(input: &str, mut f: impl FnMut(Pos, char) -> Result<T>) -> Result<Self> {
+        let width = input.lines().next().context("Empty input")?.chars().count();
+        if width == 0 {
+            bail!("Empty input");
+        }
+
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (y, line) in input.lines().enumerate() {
+            if line.chars().count() != width {
+                bail!(
+                    "Line {} has width {}, expected {}",
+                    y + 1,
+                    line.chars().count(),
+                    width
+                );
+            }
+            for (x, c) in line.chars().enumerate() {
+                cells.push(f(Pos::new(x as isize, y as isize), c)?);
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x >= 0 && pos.x < self.width as isize && pos.y >= 0 && pos.y < self.height as isize
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.contains(pos)
+            .then(|| pos.y as usize * self.width + pos.x as usize)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Iterator over every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    /// Iterator over every cell in the grid along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks_exact(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterator over the horizontally and vertically adjacent positions which are within the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .map(move |(dx, dy)| pos.offset(dx, dy))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Iterator over the horizontally, vertically and diagonally adjacent positions which are
+    /// within the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .map(move |(dx, dy)| pos.offset(dx, dy))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        let i = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos));
+        &self.cells[i]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        let i = self
+            .index_of(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos));
+        &mut self.cells[i]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::parse(input, |_pos, c| Ok(c)).unwrap()
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let grid = parse("abc\ndef\n");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], 'f');
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, -1)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        assert!(Grid::parse("abc\nde\n", |_pos, c| Ok(c)).is_err());
+        assert!(Grid::parse("", |_pos, c| Ok(c)).is_err());
+        assert!(Grid::parse("\n", |_pos, c| Ok(c)).is_err());
+        assert!(Grid::parse("\n\n", |_pos, c| Ok(c)).is_err());
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = parse("abc\ndef\n");
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = parse("abc\ndef\nghi\n");
+        let chars = |positions: Vec<Pos>| {
+            positions
+                .into_iter()
+                .map(|pos| grid[pos])
+                .collect::<String>()
+        };
+        assert_eq!(chars(grid.neighbours4(Pos::new(1, 1)).collect()), "dfbh");
+        assert_eq!(
+            chars(grid.neighbours8(Pos::new(1, 1)).collect()),
+            "abcdfghi"
+        );
+        assert_eq!(chars(grid.neighbours4(Pos::new(0, 0)).collect()), "bd");
+        assert_eq!(chars(grid.neighbours8(Pos::new(2, 2)).collect()), "efh");
+    }
+}
@@ -0,0 +1,82 @@
+use std::{fmt::Display, ops::RangeInclusive};
+
+use anyhow::{bail, Result};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod grid;
+pub mod parse;
+pub mod polygon;
+
+/// The days which have a solver.
+pub const DAYS: RangeInclusive<u32> = 1..=12;
+
+/// A solution to a single day's puzzle. The input is parsed once into `Input`, then each part
+/// computes its answer from the parsed model.
+pub trait Solver {
+    const DAY: u32;
+
+    type Input;
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part_a(input: &Self::Input) -> Result<Self::Output>;
+    fn part_b(input: &Self::Input) -> Result<Self::Output>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::A => write!(f, "A"),
+            Part::B => write!(f, "B"),
+        }
+    }
+}
+
+/// Parses the input once then solves each of the requested parts, returning the formatted answers
+/// in the same order.
+pub fn solve<S: Solver>(input: &str, parts: &[Part]) -> Result<Vec<String>> {
+    let input = S::parse(input)?;
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::A => S::part_a(&input).map(|answer| answer.to_string()),
+            Part::B => S::part_b(&input).map(|answer| answer.to_string()),
+        })
+        .collect()
+}
+
+/// Dispatches to the solver for the given day.
+pub fn solve_day(day: u32, input: &str, parts: &[Part]) -> Result<Vec<String>> {
+    match day {
+        1 => solve::<day01::Day01>(input, parts),
+        2 => solve::<day02::Day02>(input, parts),
+        3 => solve::<day03::Day03>(input, parts),
+        4 => solve::<day04::Day04>(input, parts),
+        5 => solve::<day05::Day05>(input, parts),
+        6 => solve::<day06::Day06>(input, parts),
+        7 => solve::<day07::Day07>(input, parts),
+        8 => solve::<day08::Day08>(input, parts),
+        9 => solve::<day09::Day09>(input, parts),
+        10 => solve::<day10::Day10>(input, parts),
+        11 => solve::<day11::Day11>(input, parts),
+        12 => solve::<day12::Day12>(input, parts),
+        _ => bail!("No solver for day {}", day),
+    }
+}
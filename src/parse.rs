@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use nom::{
+    branch::alt,
+    character::complete::newline,
+    combinator::{all_consuming, cut, eof, not, peek, value},
+    error::{ErrorKind, VerboseError, VerboseErrorKind},
+    multi::separated_list1,
+    sequence::terminated,
+    Parser,
+};
+
+/// Result type for the nom parsers. Contexts added with `nom::error::context` name the literal
+/// syntax expected at that point, and are quoted in error messages.
+pub type IResult<I, O> = nom::IResult<I, O, VerboseError<I>>;
+
+/// An error encountered while parsing puzzle input, located at the line and column where the
+/// parser failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    fn new(input: &str, remaining: &str, expected: String) -> Self {
+        let offset = input.len() - remaining.len();
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            source_line: input[line_start..line_end].to_string(),
+            expected,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{}", self.source_line)?;
+        write!(f, "{:>1$}", "^", self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn describe(kind: &VerboseErrorKind) -> String {
+    match kind {
+        VerboseErrorKind::Context(context) => format!("`{}`", context),
+        VerboseErrorKind::Char('\n') => "end of line".to_string(),
+        VerboseErrorKind::Char(c) => format!("`{}`", c),
+        VerboseErrorKind::Nom(ErrorKind::Digit) => "a number".to_string(),
+        VerboseErrorKind::Nom(ErrorKind::Space | ErrorKind::MultiSpace) => "whitespace".to_string(),
+        VerboseErrorKind::Nom(ErrorKind::MapRes) => "a valid value".to_string(),
+        VerboseErrorKind::Nom(ErrorKind::Eof) => "end of input".to_string(),
+        VerboseErrorKind::Nom(kind) => kind.description().to_lowercase(),
+    }
+}
+
+/// Runs a parser over the whole input, which may only be followed by trailing whitespace.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> Result<T, ParseError> {
+    let input = input.trim_end();
+    match all_consuming(parser)(input) {
+        Ok((_, result)) => Ok(result),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            // The first error is the deepest point the parser reached. Contexts added further out
+            // at that same position describe what was expected more helpfully than the kind.
+            let (remaining, kind) = &e.errors[0];
+            let expected = e
+                .errors
+                .iter()
+                .rev()
+                .find(|(pos, kind)| {
+                    pos.len() == remaining.len() && !matches!(kind, VerboseErrorKind::Nom(_))
+                })
+                .map_or(kind, |(_, kind)| kind);
+            Err(ParseError::new(input, remaining, describe(expected)))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!("Only complete parsers are used"),
+    }
+}
+
+/// Like nom's `tag`, but reports which tag was expected when it fails to match.
+pub fn tag<'a>(tag: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    move |input| {
+        nom::bytes::complete::tag(tag)(input).map_err(|e| {
+            e.map(|_: VerboseError<&str>| VerboseError {
+                errors: vec![(input, VerboseErrorKind::Context(tag))],
+            })
+        })
+    }
+}
+
+/// Parses one element per line, up to a blank line or the end of the input. Each element must
+/// span its whole line, and a malformed line is reported as an error within that line rather than
+/// ending the list early.
+pub fn lines<'a, T>(
+    element: impl Parser<&'a str, T, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_list1(
+        terminated(newline, not(newline)),
+        cut(terminated(
+            element,
+            peek(alt((value((), eof), value((), newline)))),
+        )),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{character::complete::digit1, error::context, sequence::preceded};
+
+    use super::*;
+
+    fn parse_numbers(input: &str) -> IResult<&str, Vec<&str>> {
+        lines(preceded(tag("n="), context("0-9", digit1)))(input)
+    }
+
+    #[test]
+    fn error_location() {
+        let err = parse_all("n=1\nn=2\nn=x3\n", parse_numbers).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                line: 3,
+                column: 3,
+                source_line: "n=x3".to_string(),
+                expected: "`0-9`".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 3: expected `0-9`\nn=x3\n  ^"
+        );
+    }
+
+    #[test]
+    fn missing_tag() {
+        let err = parse_all("n=1\nm=2", parse_numbers).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "`n=`");
+    }
+
+    #[test]
+    fn trailing_input() {
+        let err = parse_all("n=1 n=2\n", parse_numbers).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 4));
+        assert_eq!(err.expected, "end of line");
+
+        assert_eq!(
+            parse_all("n=1\nn=2\n\n", parse_numbers).unwrap(),
+            ["1", "2"]
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Areas of simple polygons whose vertices lie on the integer grid.
+
+use crate::grid::Pos;
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Twice the area enclosed by the polygon, using the shoelace formula. Doubling keeps the result
+/// an integer. The vertices can be in either winding order, and the last is joined back to the
+/// first.
+pub fn double_area(vertices: &[Pos]) -> usize {
+    let sum = vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum::<isize>();
+    sum.unsigned_abs()
+}
+
+/// The number of grid points on the polygon's edges.
+pub fn boundary_points(vertices: &[Pos]) -> usize {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(a, b)| gcd(a.x.abs_diff(b.x), a.y.abs_diff(b.y)))
+        .sum()
+}
+
+/// The number of grid points strictly inside the polygon, using Pick's theorem: the area is the
+/// number of interior points plus half the number of boundary points, minus one.
+///
+/// The polygon must be simple, which isn't checked: the count for one whose edges cross is
+/// meaningless. Returns `None` only when there are fewer than three vertices, the area is zero,
+/// or there are more boundary points than the area allows, as when edges double back.
+pub fn interior_points(vertices: &[Pos]) -> Option<usize> {
+    let double_area = double_area(vertices);
+    if vertices.len() < 3 || double_area == 0 {
+        return None;
+    }
+    let double_interior = (double_area + 2).checked_sub(boundary_points(vertices))?;
+    Some(double_interior / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn polygon(vertices: &[(isize, isize)]) -> Vec<Pos> {
+        vertices.iter().map(|&(x, y)| Pos::new(x, y)).collect()
+    }
+
+    #[test]
+    fn rectangle() {
+        let rectangle = polygon(&[(0, 0), (4, 0), (4, 3), (0, 3)]);
+        assert_eq!(double_area(&rectangle), 24);
+        assert_eq!(boundary_points(&rectangle), 14);
+        assert_eq!(interior_points(&rectangle), Some(6));
+
+        let reversed = rectangle.into_iter().rev().collect::<Vec<_>>();
+        assert_eq!(double_area(&reversed), 24);
+    }
+
+    #[test]
+    fn triangle() {
+        let triangle = polygon(&[(0, 0), (4, 0), (0, 4)]);
+        assert_eq!(double_area(&triangle), 16);
+        assert_eq!(boundary_points(&triangle), 12);
+        assert_eq!(interior_points(&triangle), Some(3));
+    }
+
+    #[test]
+    fn degenerate() {
+        assert_eq!(interior_points(&[]), None);
+        assert_eq!(interior_points(&polygon(&[(2, 5)])), None);
+        assert_eq!(interior_points(&polygon(&[(0, 0), (3, 0)])), None);
+        assert_eq!(interior_points(&polygon(&[(0, 0), (3, 0), (6, 0)])), None);
+    }
+}
@@ -0,0 +1,69 @@
+//! Checks every day against the answers for the puzzle inputs in `res/`, so that refactoring a
+//! solver can't silently change its results.
+
+use aoc2023::{solve_day, Part};
+
+fn check(day: u32, parts: &[Part], expected: &[&str]) {
+    let input = std::fs::read_to_string(format!("res/input{:02}.txt", day)).unwrap();
+    assert_eq!(solve_day(day, &input, parts).unwrap(), expected);
+}
+
+#[test]
+fn day01() {
+    check(1, &[Part::A, Part::B], &["54388", "53515"]);
+}
+
+#[test]
+fn day02() {
+    check(2, &[Part::A, Part::B], &["2505", "70265"]);
+}
+
+#[test]
+fn day03() {
+    check(3, &[Part::A, Part::B], &["537732", "84883664"]);
+}
+
+#[test]
+fn day04() {
+    check(4, &[Part::A, Part::B], &["25010", "9924412"]);
+}
+
+#[test]
+fn day05() {
+    check(5, &[Part::A, Part::B], &["825516882", "136096660"]);
+}
+
+#[test]
+fn day06() {
+    check(6, &[Part::A, Part::B], &["219849", "29432455"]);
+}
+
+#[test]
+fn day07() {
+    check(7, &[Part::A, Part::B], &["247815719", "248747492"]);
+}
+
+#[test]
+fn day08() {
+    check(8, &[Part::A, Part::B], &["12083", "13385272668829"]);
+}
+
+#[test]
+fn day09() {
+    check(9, &[Part::A, Part::B], &["1819125966", "1140"]);
+}
+
+#[test]
+fn day10() {
+    check(10, &[Part::A, Part::B], &["6757", "523"]);
+}
+
+#[test]
+fn day11() {
+    check(11, &[Part::A, Part::B], &["10154062", "553083047914"]);
+}
+
+#[test]
+fn day12() {
+    check(12, &[Part::A, Part::B], &["7025", "11461095383315"]);
+}